@@ -3,14 +3,31 @@
 #include "rust/cxx.h"
 #include "sys/types.h"
 
+#include <array>
 #include <compare>
 #include <concepts>
+#include <cstddef>
+#include <cstdint>
+#include <cstring>
+#include <istream>
 #include <iterator>
 #include <limits>
 #include <memory>
+#include <ostream>
 #include <ranges>
-#include <sstream>
+#include <streambuf>
+#include <string>
+#include <string_view>
 #include <type_traits>
+#include <version>
+
+// NOTE: libc++ ships a usable `<format>` before it advertises `__cpp_lib_format`
+#if __has_include(<format>) and (defined(__cpp_lib_format) or (defined(_LIBCPP_VERSION) and _LIBCPP_VERSION >= 170000))
+#include <format>
+#define CXX_AUTO_HAS_STD_FORMAT 1
+#else
+#define CXX_AUTO_HAS_STD_FORMAT 0
+#endif
 
 // NOLINTBEGIN(google-runtime-int)
 using c_char = char;
@@ -29,12 +46,45 @@ using c_off_t = off_t;
 using c_time_t = time_t;
 // NOLINTEND(google-runtime-int)
 
+// NOTE: the arithmetic and bitwise operators, each listed once as `KIND(NAME, OP)` where `KIND` is the macro applied
+// to binary, unary, or compound assignment operators; the Rust side mirrors this table in its `*_OPERATORS` tables
+// NOLINTBEGIN(cppcoreguidelines-macro-usage)
+#define CXX_AUTO_OPERATORS(BINARY, UNARY, COMPOUND_ASSIGNMENT)                                                         \
+  BINARY(plus, +)                                                                                                      \
+  BINARY(minus, -)                                                                                                     \
+  BINARY(multiplies, *)                                                                                                \
+  BINARY(divides, /)                                                                                                   \
+  BINARY(modulus, %)                                                                                                   \
+  BINARY(bit_and, &)                                                                                                   \
+  BINARY(bit_or, |)                                                                                                    \
+  BINARY(bit_xor, ^)                                                                                                   \
+  UNARY(negate, -)                                                                                                     \
+  UNARY(bit_not, ~)                                                                                                    \
+  COMPOUND_ASSIGNMENT(plus_assign, +=)                                                                                 \
+  COMPOUND_ASSIGNMENT(minus_assign, -=)                                                                                \
+  COMPOUND_ASSIGNMENT(multiplies_assign, *=)                                                                           \
+  COMPOUND_ASSIGNMENT(divides_assign, /=)                                                                              \
+  COMPOUND_ASSIGNMENT(modulus_assign, %=)                                                                              \
+  COMPOUND_ASSIGNMENT(bit_and_assign, &=)                                                                              \
+  COMPOUND_ASSIGNMENT(bit_or_assign, |=)                                                                               \
+  COMPOUND_ASSIGNMENT(bit_xor_assign, ^=)
+// NOLINTEND(cppcoreguidelines-macro-usage)
+
+namespace cxx_auto {
+// NOTE: specialize to override the detected Rust trait decisions for `T`; recognized members are `static constexpr bool`
+// values named `unpin`, `send`, `sync`, `copy`, `drop`, `default_`, `copy_new`, `move_new`, `eq`, `partial_eq`,
+// `partial_ord`, `ord`, `hash`, `debug`, `display`, `from_iterator`, and `from_str`
+template<typename T>
+struct traits
+{};
+} // namespace cxx_auto
+
 namespace cxx_auto::detection {
 template<typename T, typename... U>
 concept same_as_any_of = (std::same_as<T, U> or ...);
 
-template<typename T>
-concept has_operator_equal = requires(T const& lhs, T const& rhs) { //
+template<typename T, typename U = T>
+concept has_operator_equal = requires(T const& lhs, U const& rhs) { //
   {
     lhs == rhs
   } -> std::same_as<bool>;
@@ -47,8 +97,8 @@ concept has_operator_not_equal = requires(T const& lhs, T const& rhs) { //
   } -> std::same_as<bool>;
 };
 
-template<typename T>
-concept has_operator_less_than = requires(T const& lhs, T const& rhs) { //
+template<typename T, typename U = T>
+concept has_operator_less_than = requires(T const& lhs, U const& rhs) { //
   {
     lhs < rhs
   } -> std::same_as<bool>;
@@ -75,9 +125,67 @@ concept has_operator_greater_than_or_equal = requires(T const& lhs, T const& rhs
   } -> std::same_as<bool>;
 };
 
+template<typename T, typename U = T>
+concept has_operator_three_way_comparison = requires(T const& lhs, U const& rhs) { //
+  requires same_as_any_of<decltype(lhs <=> rhs), std::partial_ordering, std::weak_ordering, std::strong_ordering>;
+};
+
+template<typename T, typename U = T>
+concept has_operator_three_way_comparison_weak = requires(T const& lhs, U const& rhs) { //
+  requires same_as_any_of<decltype(lhs <=> rhs), std::weak_ordering, std::strong_ordering>;
+};
+
+template<typename T, typename U = T>
+concept has_operator_three_way_comparison_strong = requires(T const& lhs, U const& rhs) { //
+  requires std::same_as<decltype(lhs <=> rhs), std::strong_ordering>;
+};
+
+// NOLINTBEGIN(cppcoreguidelines-macro-usage, bugprone-macro-parentheses)
+#define CXX_AUTO_DETECTION_BINARY_OPERATOR(NAME, OP)                                                                   \
+  template<typename T>                                                                                                 \
+  concept has_operator_##NAME = requires(T const& lhs, T const& rhs) {                                                 \
+    {                                                                                                                  \
+      lhs OP rhs                                                                                                       \
+    } -> std::convertible_to<T>;                                                                                       \
+  };
+#define CXX_AUTO_DETECTION_UNARY_OPERATOR(NAME, OP)                                                                    \
+  template<typename T>                                                                                                 \
+  concept has_operator_##NAME = requires(T const& arg) {                                                               \
+    {                                                                                                                  \
+      OP arg                                                                                                           \
+    } -> std::convertible_to<T>;                                                                                       \
+  };
+#define CXX_AUTO_DETECTION_COMPOUND_ASSIGNMENT_OPERATOR(NAME, OP)                                                      \
+  template<typename T>                                                                                                 \
+  concept has_operator_##NAME = requires(T& lhs, T const& rhs) {                                                       \
+    lhs OP rhs;                                                                                                        \
+  };
+CXX_AUTO_OPERATORS(
+  CXX_AUTO_DETECTION_BINARY_OPERATOR,
+  CXX_AUTO_DETECTION_UNARY_OPERATOR,
+  CXX_AUTO_DETECTION_COMPOUND_ASSIGNMENT_OPERATOR
+)
+#undef CXX_AUTO_DETECTION_BINARY_OPERATOR
+#undef CXX_AUTO_DETECTION_UNARY_OPERATOR
+#undef CXX_AUTO_DETECTION_COMPOUND_ASSIGNMENT_OPERATOR
+// NOLINTEND(cppcoreguidelines-macro-usage, bugprone-macro-parentheses)
+
+template<typename T, typename Index>
+concept has_operator_subscript_const = requires(T const& arg, Index index) { //
+  requires std::is_lvalue_reference_v<decltype(arg[index])>;
+};
+
+template<typename T, typename Index>
+concept has_operator_subscript = requires(T& arg, Index index) { //
+  requires std::is_lvalue_reference_v<decltype(arg[index])>;
+  requires not std::is_const_v<std::remove_reference_t<decltype(arg[index])>>;
+};
+
 template<typename T>
-concept has_operator_three_way_comparison = requires(T const& lhs, T const& rhs) { //
-  requires same_as_any_of<decltype(lhs <=> rhs), std::partial_ordering, std::strong_ordering>;
+concept has_size = requires(T const& arg) { //
+  {
+    arg.size()
+  } -> std::convertible_to<std::size_t>;
 };
 
 template<typename T>
@@ -115,11 +223,29 @@ concept has_operator_ostream_left_shift = requires(T const& arg, std::ostream& o
   } -> std::same_as<std::ostream&>;
 };
 
+template<typename T>
+concept has_operator_istream_right_shift = requires(T& arg, std::istream& is) { //
+  {
+    is >> arg
+  } -> std::same_as<std::istream&>;
+};
+
+#if CXX_AUTO_HAS_STD_FORMAT
+template<typename T>
+concept has_std_formatter = std::semiregular<std::formatter<T, char>> and
+                            requires(std::formatter<T, char> formatter, T const& arg, std::format_context& context) { //
+                              formatter.format(arg, context);
+                            };
+#else
+template<typename T>
+concept has_std_formatter = false;
+#endif
+
 template<typename T, typename It>
 concept is_constructible_from_iterator = requires(It first, It last) { //
   requires std::input_iterator<It>;
   {
-    T{ first, last }
+    T(first, last)
   } -> std::same_as<T>;
 };
 
@@ -162,6 +288,16 @@ cxx_abi_size() noexcept -> size_t
   return sizeof(T);
 }
 
+// NOTE: `N` and `A` are deduced from the bridge declaration, so bindings generated for a stale layout fail to compile
+template<typename T, size_t N, size_t A>
+[[gnu::always_inline]]
+constexpr static inline auto
+cxx_assert_abi_layout(::std::array<::std::uint8_t, N> const&, ::std::array<::std::uint8_t, A> const&) noexcept -> void
+{
+  static_assert(sizeof(T) == N, "cxx-auto: the C++ size has changed since the bindings were generated");
+  static_assert(alignof(T) == A, "cxx-auto: the C++ alignment has changed since the bindings were generated");
+}
+
 template<typename T, typename... Args>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
@@ -170,6 +306,24 @@ cxx_is_constructible() noexcept -> bool
   return std::is_constructible_v<T, Args...>;
 }
 
+// NOTE: only implicit conversions are considered, i.e., non-`explicit` converting constructors of `T` and
+// non-`explicit` conversion operators of `From`
+template<typename T, typename From>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_convertible_from() noexcept -> bool
+{
+  return std::is_convertible_v<From const&, T>;
+}
+
+template<typename T, typename From>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_nothrow_convertible_from() noexcept -> bool
+{
+  return std::is_nothrow_convertible_v<From const&, T>;
+}
+
 template<typename T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
@@ -194,6 +348,22 @@ cxx_is_move_constructible() noexcept -> bool
   return std::is_move_constructible_v<T>;
 }
 
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_copy_assignable() noexcept -> bool
+{
+  return std::is_copy_assignable_v<T>;
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_move_assignable() noexcept -> bool
+{
+  return std::is_move_assignable_v<T>;
+}
+
 template<typename T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
@@ -234,12 +404,12 @@ cxx_is_equality_comparable() noexcept -> bool
   return std::equality_comparable<T>;
 }
 
-template<typename T>
+template<typename T, typename U = T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
 cxx_has_operator_equal() noexcept -> bool
 {
-  return detection::has_operator_equal<T>;
+  return detection::has_operator_equal<T, U>;
 }
 
 template<typename T>
@@ -250,12 +420,12 @@ cxx_has_operator_not_equal() noexcept -> bool
   return detection::has_operator_not_equal<T>;
 }
 
-template<typename T>
+template<typename T, typename U = T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
 cxx_has_operator_less_than() noexcept -> bool
 {
-  return detection::has_operator_less_than<T>;
+  return detection::has_operator_less_than<T, U>;
 }
 
 template<typename T>
@@ -282,22 +452,77 @@ cxx_has_operator_greater_than_or_equal() noexcept -> bool
   return detection::has_operator_greater_than_or_equal<T>;
 }
 
-template<typename T>
+template<typename T, typename U = T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
 cxx_has_operator_three_way_comparison() noexcept -> bool
 {
-  return detection::has_operator_three_way_comparison<T> or
-         (not detection::has_operator_three_way_comparison<T> and detection::has_operator_less_than<T> and
-          detection::has_operator_equal<T>);
+  return detection::has_operator_three_way_comparison<T, U> or
+         (not detection::has_operator_three_way_comparison<T, U> and detection::has_operator_less_than<T, U> and
+          detection::has_operator_equal<T, U>);
+}
+
+// NOLINTBEGIN(cppcoreguidelines-macro-usage)
+#define CXX_AUTO_HAS_OPERATOR(NAME, OP)                                                                                \
+  template<typename T>                                                                                                 \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_operator_##NAME() noexcept -> bool                                              \
+  {                                                                                                                    \
+    return detection::has_operator_##NAME<T>;                                                                          \
+  }
+CXX_AUTO_OPERATORS(CXX_AUTO_HAS_OPERATOR, CXX_AUTO_HAS_OPERATOR, CXX_AUTO_HAS_OPERATOR)
+#undef CXX_AUTO_HAS_OPERATOR
+// NOLINTEND(cppcoreguidelines-macro-usage)
+
+template<typename T, typename Index>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_has_operator_subscript_const() noexcept -> bool
+{
+  return detection::has_operator_subscript_const<T, Index>;
+}
+
+template<typename T, typename Index>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_has_operator_subscript() noexcept -> bool
+{
+  return detection::has_operator_subscript<T, Index>;
 }
 
 template<typename T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
+cxx_has_size() noexcept -> bool
+{
+  return detection::has_size<T>;
+}
+
+template<typename T, typename U = T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
 cxx_is_partially_ordered() noexcept -> bool
 {
-  return cxx_has_operator_three_way_comparison<T>();
+  return cxx_has_operator_three_way_comparison<T, U>();
+}
+
+// NOTE: without `operator<=>`, the ordering is only known to be total when `std::totally_ordered_with` holds
+template<typename T, typename U = T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_weakly_ordered() noexcept -> bool
+{
+  return detection::has_operator_three_way_comparison_weak<T, U> or
+         (not detection::has_operator_three_way_comparison<T, U> and std::totally_ordered_with<T, U>);
+}
+
+template<typename T, typename U = T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_strongly_ordered() noexcept -> bool
+{
+  return detection::has_operator_three_way_comparison_strong<T, U> or
+         (not detection::has_operator_three_way_comparison<T, U> and std::totally_ordered_with<T, U>);
 }
 
 template<typename T>
@@ -324,15 +549,106 @@ cxx_is_debuggable() noexcept -> bool
   return detection::has_operator_ostream_left_shift<T>;
 }
 
+// NOTE: extraction needs an object to extract into, so only default constructible types are parsable
 template<typename T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
-cxx_is_displayable() noexcept -> bool
+cxx_is_parsable() noexcept -> bool
+{
+  return detection::has_operator_istream_right_shift<T> and std::is_default_constructible_v<T>;
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_string_convertible() noexcept -> bool
 {
   return detection::has_to_string<T> or detection::has_operator_std_string<T> or
          detection::has_operator_std_string_view<T>;
 }
 
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_displayable() noexcept -> bool
+{
+  return cxx_is_string_convertible<T>() or detection::has_std_formatter<T>;
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_has_std_formatter() noexcept -> bool
+{
+  return detection::has_std_formatter<T>;
+}
+
+// NOTE: only ranges whose const iterators yield lvalue references to stored elements are accepted, since the cursor
+// walks `T const` and the Rust iterator hands out references which must remain valid after the cursor advances
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_iterable() noexcept -> bool
+{
+  return requires {
+    requires detection::is_iterable<T const>;
+    requires std::input_iterator<std::ranges::iterator_t<T const>>;
+    requires std::is_lvalue_reference_v<std::iter_reference_t<std::ranges::iterator_t<T const>>>;
+    requires std::same_as<
+      std::remove_cvref_t<std::iter_reference_t<std::ranges::iterator_t<T const>>>,
+      std::ranges::range_value_t<T const>>;
+  };
+}
+
+// NOTE: the element pointers are what Rust passes after collecting an iterator into a contiguous buffer
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_constructible_from_iterator() noexcept -> bool
+{
+  return requires { //
+    requires detection::is_constructible_from_iterator<T, std::ranges::range_value_t<T> const*>;
+  };
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_standard_layout() noexcept -> bool
+{
+  return std::is_standard_layout_v<T>;
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_is_enum() noexcept -> bool
+{
+  return std::is_enum_v<T>;
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_enum_is_signed() noexcept -> bool
+{
+  if constexpr (cxx_is_enum<T>()) {
+    return std::is_signed_v<std::underlying_type_t<T>>;
+  } else {
+    return false;
+  }
+}
+
+// NOTE: signed values are sign-extended so that Rust can recover them with `as i64`
+template<typename T>
+requires(cxx_is_enum<T>())
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+cxx_enumerator_bits(T value) noexcept -> uint64_t
+{
+  return static_cast<uint64_t>(static_cast<std::underlying_type_t<T>>(value));
+}
+
 template<typename T>
 [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
 constexpr static inline auto
@@ -346,7 +662,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_unpin() noexcept -> bool
 {
-  return cxx_is_trivially_movable<T>();
+  if constexpr (requires { traits<T>::unpin; }) {
+    return traits<T>::unpin;
+  } else {
+    return cxx_is_trivially_movable<T>();
+  }
 }
 
 template<typename T>
@@ -354,7 +674,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_send() noexcept -> bool
 {
-  return false;
+  if constexpr (requires { traits<T>::send; }) {
+    return traits<T>::send;
+  } else {
+    return false;
+  }
 }
 
 template<typename T>
@@ -362,7 +686,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_sync() noexcept -> bool
 {
-  return false;
+  if constexpr (requires { traits<T>::sync; }) {
+    return traits<T>::sync;
+  } else {
+    return false;
+  }
 }
 
 template<typename T>
@@ -370,7 +698,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_drop() noexcept -> bool
 {
-  return cxx_is_destructible<T>() and not cxx_is_trivially_destructible<T>();
+  if constexpr (requires { traits<T>::drop; }) {
+    return traits<T>::drop;
+  } else {
+    return cxx_is_destructible<T>() and not cxx_is_trivially_destructible<T>();
+  }
 }
 
 template<typename T>
@@ -378,7 +710,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_copy() noexcept -> bool
 {
-  return cxx_is_trivially_copyable<T>() and cxx_is_trivially_movable<T>() and not rust_should_impl_drop<T>();
+  if constexpr (requires { traits<T>::copy; }) {
+    return traits<T>::copy;
+  } else {
+    return cxx_is_trivially_copyable<T>() and cxx_is_trivially_movable<T>() and not rust_should_impl_drop<T>();
+  }
 }
 
 template<typename T>
@@ -386,7 +722,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_default() noexcept -> bool
 {
-  return cxx_is_default_constructible<T>();
+  if constexpr (requires { traits<T>::default_; }) {
+    return traits<T>::default_;
+  } else {
+    return cxx_is_default_constructible<T>();
+  }
 }
 
 template<typename T>
@@ -394,7 +734,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_moveref_copy_new() noexcept -> bool
 {
-  return cxx_is_copy_constructible<T>();
+  if constexpr (requires { traits<T>::copy_new; }) {
+    return traits<T>::copy_new;
+  } else {
+    return cxx_is_copy_constructible<T>();
+  }
 }
 
 template<typename T>
@@ -402,7 +746,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_moveref_move_new() noexcept -> bool
 {
-  return cxx_is_move_constructible<T>();
+  if constexpr (requires { traits<T>::move_new; }) {
+    return traits<T>::move_new;
+  } else {
+    return cxx_is_move_constructible<T>();
+  }
 }
 
 template<typename T>
@@ -410,7 +758,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_eq() noexcept -> bool
 {
-  return cxx_is_equality_comparable<T>();
+  if constexpr (requires { traits<T>::eq; }) {
+    return traits<T>::eq;
+  } else {
+    return cxx_is_equality_comparable<T>();
+  }
 }
 
 template<typename T>
@@ -418,7 +770,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_partial_eq() noexcept -> bool
 {
-  return cxx_has_operator_equal<T>();
+  if constexpr (requires { traits<T>::partial_eq; }) {
+    return traits<T>::partial_eq;
+  } else {
+    return cxx_has_operator_equal<T>();
+  }
 }
 
 template<typename T>
@@ -426,8 +782,12 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_partial_ord() noexcept -> bool
 {
-  return cxx_has_operator_three_way_comparison<T>() or
-         (cxx_has_operator_less_than<T>() and cxx_has_operator_equal<T>());
+  if constexpr (requires { traits<T>::partial_ord; }) {
+    return traits<T>::partial_ord;
+  } else {
+    return cxx_has_operator_three_way_comparison<T>() or
+           (cxx_has_operator_less_than<T>() and cxx_has_operator_equal<T>());
+  }
 }
 
 template<typename T>
@@ -435,7 +795,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_ord() noexcept -> bool
 {
-  return cxx_is_totally_ordered<T>();
+  if constexpr (requires { traits<T>::ord; }) {
+    return traits<T>::ord;
+  } else {
+    return cxx_is_strongly_ordered<T>();
+  }
 }
 
 template<typename T>
@@ -443,7 +807,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_hash() noexcept -> bool
 {
-  return cxx_is_hashable<T>();
+  if constexpr (requires { traits<T>::hash; }) {
+    return traits<T>::hash;
+  } else {
+    return cxx_is_hashable<T>();
+  }
 }
 
 template<typename T>
@@ -451,7 +819,11 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_debug() noexcept -> bool
 {
-  return cxx_is_debuggable<T>();
+  if constexpr (requires { traits<T>::debug; }) {
+    return traits<T>::debug;
+  } else {
+    return cxx_is_debuggable<T>();
+  }
 }
 
 template<typename T>
@@ -459,7 +831,35 @@ template<typename T>
 constexpr static inline auto
 rust_should_impl_display() noexcept -> bool
 {
-  return cxx_is_displayable<T>();
+  if constexpr (requires { traits<T>::display; }) {
+    return traits<T>::display;
+  } else {
+    return cxx_is_displayable<T>();
+  }
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+rust_should_impl_from_iterator() noexcept -> bool
+{
+  if constexpr (requires { traits<T>::from_iterator; }) {
+    return traits<T>::from_iterator;
+  } else {
+    return cxx_is_constructible_from_iterator<T>();
+  }
+}
+
+template<typename T>
+[[nodiscard]] [[gnu::always_inline]] [[gnu::const]]
+constexpr static inline auto
+rust_should_impl_from_str() noexcept -> bool
+{
+  if constexpr (requires { traits<T>::from_str; }) {
+    return traits<T>::from_str;
+  } else {
+    return cxx_is_parsable<T>() and rust_should_impl_moveref_move_new<T>();
+  }
 }
 
 } // namespace cxx_auto
@@ -474,6 +874,24 @@ cxx_placement_new(T* This [[clang::lifetimebound]], Args&&... args) noexcept ->
   new (This) T(std::forward<Args>(args)...);
 }
 
+template<typename T, typename... Args>
+requires(cxx_is_constructible<T, Args...>())
+[[gnu::always_inline]]
+static inline auto
+cxx_make_unique(Args&&... args) noexcept -> std::unique_ptr<T>
+{
+  return std::make_unique<T>(std::forward<Args>(args)...);
+}
+
+template<typename T, typename... Args>
+requires(cxx_is_constructible<T, Args...>())
+[[gnu::always_inline]]
+static inline auto
+cxx_make_shared(Args&&... args) noexcept -> std::shared_ptr<T>
+{
+  return std::make_shared<T>(std::forward<Args>(args)...);
+}
+
 template<typename T>
 requires(cxx_is_default_constructible<T>())
 [[gnu::always_inline]]
@@ -483,6 +901,44 @@ cxx_default_new(T* This [[clang::lifetimebound]]) noexcept -> void
   cxx_placement_new(This);
 }
 
+template<typename T>
+requires(cxx_is_constructible_from_iterator<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_from_iter_new(
+  T* This [[clang::lifetimebound]],
+  std::ranges::range_value_t<T> const* first,
+  std::ranges::range_value_t<T> const* last
+) noexcept -> void
+{
+  new (This) T(first, last);
+}
+
+// NOTE: returning `that` copy-initializes the result, which is then materialized in place without a move
+template<typename T, typename From>
+requires(cxx_is_convertible_from<T, From>())
+[[gnu::always_inline]]
+static inline auto
+cxx_implicit_convert(From const& that) noexcept(cxx_is_nothrow_convertible_from<T, From>()) -> T
+{
+  return that;
+}
+
+// NOTE: `This` is left uninitialized if the conversion throws, which is reported by returning `false`
+template<typename T, typename From>
+requires(cxx_is_convertible_from<T, From>())
+[[gnu::always_inline]]
+static inline auto
+cxx_convert_new(T* This [[clang::lifetimebound]], From const& that) noexcept -> bool
+{
+  try {
+    new (This) T(cxx_implicit_convert<T>(that));
+  } catch (...) {
+    return false;
+  }
+  return true;
+}
+
 template<typename T>
 requires(cxx_is_copy_constructible<T>())
 [[gnu::always_inline]]
@@ -493,6 +949,15 @@ requires std::is_lvalue_reference_v<decltype(that)>
   new (This) T(that);
 }
 
+template<typename T>
+requires(cxx_is_copy_assignable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_copy_assign(T& This, T const& that) noexcept -> void
+{
+  This = that;
+}
+
 template<typename T>
 requires(cxx_is_move_constructible<T>())
 [[gnu::always_inline]]
@@ -503,6 +968,16 @@ requires std::is_rvalue_reference_v<decltype(that)>
   new (This) T(std::forward<T>(that));
 }
 
+template<typename T>
+requires(cxx_is_move_assignable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_move_assign(T& This, T&& that) noexcept -> void
+requires std::is_rvalue_reference_v<decltype(that)>
+{
+  This = std::forward<T>(that);
+}
+
 template<typename T>
 requires(cxx_is_destructible<T>())
 [[gnu::always_inline]]
@@ -512,11 +987,11 @@ cxx_destruct(T* This [[clang::lifetimebound]]) -> void
   std::destroy_at(This);
 }
 
-template<typename T>
-requires(cxx_has_operator_equal<T>())
+template<typename T, typename U = T>
+requires(cxx_has_operator_equal<T, U>())
 [[gnu::always_inline]]
 static inline auto
-cxx_operator_equal(T const& This [[clang::lifetimebound]], T const& That [[clang::lifetimebound]]) noexcept -> bool
+cxx_operator_equal(T const& This [[clang::lifetimebound]], U const& That [[clang::lifetimebound]]) noexcept -> bool
 {
   return (This == That);
 }
@@ -571,96 +1046,430 @@ cxx_operator_greater_than_or_equal(
   return (This >= That);
 }
 
-template<typename T>
-requires(detection::has_operator_three_way_comparison<T>)
+// NOTE: the values must match the variants of `cxx_auto::cmp::CxxOrdering` on the Rust side
+enum class cxx_ordering : std::int8_t
+{
+  less = -1,
+  equivalent = 0,
+  greater = 1,
+  unordered = 2,
+};
+
+template<typename T, typename U = T>
+requires(detection::has_operator_three_way_comparison<T, U>)
 [[gnu::always_inline]]
 static inline auto
 cxx_operator_three_way_comparison(
   T const& This [[clang::lifetimebound]],
-  T const& That [[clang::lifetimebound]]
-) noexcept -> int8_t
+  U const& That [[clang::lifetimebound]]
+) noexcept -> cxx_ordering
 {
   auto result = (This <=> That);
   if (result < 0) {
-    return -1;
+    return cxx_ordering::less;
   } else if (result > 0) { // NOLINT(llvm-else-after-return, readability-else-after-return)
-    return 1;
+    return cxx_ordering::greater;
   } else if (result == 0) {
-    return 0;
+    return cxx_ordering::equivalent;
   } else {
-    return std::numeric_limits<int8_t>::max();
+    return cxx_ordering::unordered;
   }
 }
 
-template<typename T>
-requires(not detection::has_operator_three_way_comparison<T> and detection::has_operator_less_than<T> and detection::has_operator_equal<T>)
+template<typename T, typename U = T>
+requires(
+  not detection::has_operator_three_way_comparison<T, U> and detection::has_operator_less_than<T, U> and
+  detection::has_operator_equal<T, U>)
 [[gnu::always_inline]]
 static inline auto
 cxx_operator_three_way_comparison(
   T const& This [[clang::lifetimebound]],
-  T const& That [[clang::lifetimebound]]
-) noexcept -> int8_t
+  U const& That [[clang::lifetimebound]]
+) noexcept -> cxx_ordering
 {
-  auto le = (This < That);
-  auto eq = (This == That);
-
-  if (le and not eq) {
-    return -1;
-  } else if (not le and not eq) { // NOLINT(llvm-else-after-return, readability-else-after-return)
-    return 1;
-  } else if (not le and eq) {
-    return 0;
+  if (This == That) {
+    return cxx_ordering::equivalent;
+  } else if (This < That) { // NOLINT(llvm-else-after-return, readability-else-after-return)
+    return cxx_ordering::less;
+  }
+  // NOTE: without the reversed `operator<`, an unordered pair is indistinguishable from a greater one
+  if constexpr (detection::has_operator_less_than<U, T>) {
+    return (That < This) ? cxx_ordering::greater : cxx_ordering::unordered;
   } else {
-    return std::numeric_limits<int8_t>::max();
+    return cxx_ordering::greater;
   }
 }
 
-template<typename T>
-requires(detection::is_std_hashable<T>)
-[[gnu::always_inline]]
+// NOTE: the helpers are `noexcept`, so an exception escaping from a C++ operator calls `std::terminate`
+// NOLINTBEGIN(cppcoreguidelines-macro-usage, bugprone-macro-parentheses)
+#define CXX_AUTO_BINARY_OPERATOR(NAME, OP)                                                                             \
+  template<typename T>                                                                                                 \
+  requires(cxx_has_operator_##NAME<T>())                                                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T* This [[clang::lifetimebound]], T const& lhs, T const& rhs) noexcept -> void\
+  {                                                                                                                    \
+    new (This) T(lhs OP rhs);                                                                                          \
+  }
+#define CXX_AUTO_UNARY_OPERATOR(NAME, OP)                                                                              \
+  template<typename T>                                                                                                 \
+  requires(cxx_has_operator_##NAME<T>())                                                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T* This [[clang::lifetimebound]], T const& arg) noexcept -> void              \
+  {                                                                                                                    \
+    new (This) T(OP arg);                                                                                              \
+  }
+#define CXX_AUTO_COMPOUND_ASSIGNMENT_OPERATOR(NAME, OP)                                                                \
+  template<typename T>                                                                                                 \
+  requires(cxx_has_operator_##NAME<T>())                                                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T& This [[clang::lifetimebound]], T const& That) noexcept -> void             \
+  {                                                                                                                    \
+    This OP That;                                                                                                      \
+  }
+CXX_AUTO_OPERATORS(CXX_AUTO_BINARY_OPERATOR, CXX_AUTO_UNARY_OPERATOR, CXX_AUTO_COMPOUND_ASSIGNMENT_OPERATOR)
+#undef CXX_AUTO_BINARY_OPERATOR
+#undef CXX_AUTO_UNARY_OPERATOR
+#undef CXX_AUTO_COMPOUND_ASSIGNMENT_OPERATOR
+// NOLINTEND(cppcoreguidelines-macro-usage, bugprone-macro-parentheses)
+
+template<typename T, typename Index>
+requires(cxx_has_operator_subscript_const<T, Index>())
+[[gnu::always_inline]]
+static inline auto
+cxx_operator_subscript_const(T const& This [[clang::lifetimebound]], Index index) noexcept -> decltype(auto)
+{
+  return This[index];
+}
+
+template<typename T, typename Index>
+requires(cxx_has_operator_subscript<T, Index>())
+[[gnu::always_inline]]
+static inline auto
+cxx_operator_subscript(T& This [[clang::lifetimebound]], Index index) noexcept -> decltype(auto)
+{
+  return This[index];
+}
+
+template<typename T>
+requires(cxx_has_size<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_size(T const& This) noexcept -> size_t
+{
+  return This.size();
+}
+
+template<typename T>
+requires(detection::is_std_hashable<T>)
+[[gnu::always_inline]]
 static inline auto
 cxx_hash(T const& This [[clang::lifetimebound]]) noexcept -> size_t
 {
   return std::hash<T>{}(This);
 }
 
-template<typename T>
+// NOTE: `W` is the opaque `CxxFmtWriter` declared by the generated bridge, whose `write` forwards to the borrowed Rust
+// `Formatter`; it is a template parameter since every bridge declares its own writer type
+template<typename W>
+[[gnu::always_inline]]
+static inline auto
+cxx_fmt_write_all(W& writer, std::string_view chunk) noexcept -> bool
+{
+  if (chunk.empty()) {
+    return true;
+  }
+  // NOLINTNEXTLINE(cppcoreguidelines-pro-type-reinterpret-cast)
+  auto const* data = reinterpret_cast<std::uint8_t const*>(chunk.data());
+  return writer.write(::rust::Slice<std::uint8_t const>{ data, chunk.size() });
+}
+
+// NOTE: streams formatted output to Rust in fixed-size chunks instead of collecting it into a string first
+template<typename W>
+class cxx_fmt_streambuf final : public std::streambuf
+{
+public:
+  explicit cxx_fmt_streambuf(W& writer) noexcept : writer{ writer }
+  {
+    setp(buffer.data(), buffer.data() + buffer.size());
+  }
+
+  cxx_fmt_streambuf(cxx_fmt_streambuf const&) = delete;
+  cxx_fmt_streambuf(cxx_fmt_streambuf&&) = delete;
+  auto operator=(cxx_fmt_streambuf const&) -> cxx_fmt_streambuf& = delete;
+  auto operator=(cxx_fmt_streambuf&&) -> cxx_fmt_streambuf& = delete;
+  ~cxx_fmt_streambuf() override = default;
+
+  [[nodiscard]] auto finish() noexcept -> bool
+  {
+    return flush(true);
+  }
+
+protected:
+  auto overflow(int_type ch) -> int_type override
+  {
+    if (not flush(false)) {
+      return traits_type::eof();
+    }
+    if (not traits_type::eq_int_type(ch, traits_type::eof())) {
+      *pptr() = traits_type::to_char_type(ch);
+      pbump(1);
+    }
+    return traits_type::not_eof(ch);
+  }
+
+  auto sync() -> int override
+  {
+    return flush(false) ? 0 : -1;
+  }
+
+private:
+  // NOTE: Rust can only format whole characters, so a trailing incomplete UTF-8 sequence is held back until the next
+  // flush (or until the final one, where Rust replaces it)
+  [[nodiscard]] static auto utf8_boundary(char const* first, char const* last) noexcept -> char const*
+  {
+    for (char const* lead = last; lead != first and last - lead < 4;) {
+      --lead;
+      auto const byte = static_cast<unsigned char>(*lead);
+      if ((byte & 0xC0U) == 0x80U) {
+        continue;
+      }
+      auto const width = byte < 0x80U ? 1 : byte < 0xE0U ? 2 : byte < 0xF0U ? 3 : 4;
+      return last - lead < width ? lead : last;
+    }
+    return last;
+  }
+
+  [[nodiscard]] auto flush(bool final) noexcept -> bool
+  {
+    if (failed) {
+      return false;
+    }
+    char const* first = pbase();
+    char const* last = pptr();
+    char const* end = final ? last : utf8_boundary(first, last);
+    if (not cxx_fmt_write_all(writer, std::string_view(first, static_cast<std::size_t>(end - first)))) {
+      failed = true;
+      return false;
+    }
+    auto const rest = static_cast<std::size_t>(last - end);
+    std::memmove(buffer.data(), end, rest);
+    setp(buffer.data(), buffer.data() + buffer.size());
+    pbump(static_cast<int>(rest));
+    return true;
+  }
+
+  W& writer;
+  bool failed = false;
+  std::array<char, 256> buffer{};
+};
+
+template<typename T, typename W>
 requires(detection::has_operator_ostream_left_shift<T>)
 [[gnu::always_inline]]
 static inline auto
-cxx_debug(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
+cxx_debug(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool
 {
-  std::ostringstream os;
+  cxx_fmt_streambuf<W> streambuf{ writer };
+  std::ostream os{ &streambuf };
   os << This;
-  return rust::String::lossy(os.str());
+  return streambuf.finish();
 }
 
-template<typename T>
+template<typename T, typename W>
 requires(detection::has_to_string<T>)
 [[gnu::always_inline]]
 static inline auto
-cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
+cxx_display(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool
 {
-  return rust::String::lossy(std::to_string(This));
+  return cxx_fmt_write_all(writer, std::to_string(This));
 }
 
-template<typename T>
+template<typename T, typename W>
 requires(not detection::has_to_string<T> and detection::has_operator_std_string<T>)
 [[gnu::always_inline]]
 static inline auto
-cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
+cxx_display(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool
 {
-  return rust::String::lossy(This.operator std::string());
+  return cxx_fmt_write_all(writer, This.operator std::string());
 }
 
-// FIXME: optimize this to use `&str` instead of `String`
-template<typename T>
+// NOTE: the view is borrowed by Rust for the duration of the call, so nothing is copied
+template<typename T, typename W>
 requires(not detection::has_to_string<T> and not detection::has_operator_std_string<T> and detection::has_operator_std_string_view<T>)
 [[gnu::always_inline]]
 static inline auto
-cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
+cxx_display(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool
+{
+  return cxx_fmt_write_all(writer, This.operator std::string_view());
+}
+
+// NOTE: `spec` is a complete replacement field (e.g., `{:>10}`) built from the flags of the Rust `Formatter`; the
+// result is `0` on success, `1` if formatting or writing failed, and `2` if the `std::formatter` rejected `spec`, in
+// which case nothing has been written (see `cxx_auto::fmt::write_std_format`)
+template<typename T, typename W>
+requires(detection::has_std_formatter<T>)
+[[gnu::always_inline]]
+static inline auto
+cxx_display_format(T const& This [[clang::lifetimebound]], ::rust::Str spec, W& writer) noexcept -> std::uint8_t
+{
+#if CXX_AUTO_HAS_STD_FORMAT
+  auto const format = std::string_view{ spec.data(), spec.size() };
+  try {
+    std::format_parse_context parse_context{ format.substr(format.starts_with("{:") ? 2 : 1) };
+    // NOTE: a formatter which stops before the closing brace has not consumed the whole spec
+    auto const last = std::formatter<T, char>{}.parse(parse_context);
+    if (last != parse_context.end() and *last != '}') {
+      return 2;
+    }
+  } catch (std::format_error const&) {
+    return 2;
+  }
+  cxx_fmt_streambuf<W> streambuf{ writer };
+  try {
+    std::vformat_to(std::ostreambuf_iterator<char>{ &streambuf }, format, std::make_format_args(This));
+  } catch (std::format_error const&) {
+    return 1;
+  }
+  return streambuf.finish() ? 0 : 1;
+#else
+  static_cast<void>(This);
+  static_cast<void>(spec);
+  static_cast<void>(writer);
+  return 1;
+#endif
+}
+
+template<typename T, typename W>
+requires(
+  not detection::has_to_string<T> and not detection::has_operator_std_string<T> and
+  not detection::has_operator_std_string_view<T> and detection::has_std_formatter<T>)
+[[gnu::always_inline]]
+static inline auto
+cxx_display(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool
+{
+  return cxx_display_format(This, "{}", writer) == 0;
+}
+
+// NOTE: `result` is the value returned by a formatting member (e.g., `debug_string()`), written as-is if it is a string
+template<typename R, typename W>
+requires(std::convertible_to<R const&, std::string_view>)
+[[gnu::always_inline]]
+static inline auto
+cxx_fmt_member(R const& result, W& writer) noexcept -> bool
+{
+  return cxx_fmt_write_all(writer, std::string_view{ result });
+}
+
+template<typename R, typename W>
+requires(not std::convertible_to<R const&, std::string_view> and detection::has_operator_ostream_left_shift<R>)
+[[gnu::always_inline]]
+static inline auto
+cxx_fmt_member(R const& result, W& writer) noexcept -> bool
+{
+  return cxx_debug(result, writer);
+}
+
+// NOTE: the values must match the variants of `cxx_auto::parse::ParseError` on the Rust side
+enum class cxx_parse_status : std::uint8_t
+{
+  ok = 0,
+  fail = 1,
+  bad = 2,
+  trailing = 3,
+};
+
+// NOTE: reads directly from the Rust string, which is borrowed for the duration of the extraction
+class cxx_parse_streambuf final : public std::streambuf
+{
+public:
+  explicit cxx_parse_streambuf(::rust::Str text) noexcept
+  {
+    // NOLINTNEXTLINE(cppcoreguidelines-pro-type-const-cast)
+    auto* data = const_cast<char*>(text.data());
+    setg(data, data, data + text.size());
+  }
+
+  cxx_parse_streambuf(cxx_parse_streambuf const&) = delete;
+  cxx_parse_streambuf(cxx_parse_streambuf&&) = delete;
+  auto operator=(cxx_parse_streambuf const&) -> cxx_parse_streambuf& = delete;
+  auto operator=(cxx_parse_streambuf&&) -> cxx_parse_streambuf& = delete;
+  ~cxx_parse_streambuf() override = default;
+
+  [[nodiscard]] auto remaining() const noexcept -> std::string_view
+  {
+    return { gptr(), static_cast<std::size_t>(egptr() - gptr()) };
+  }
+};
+
+template<typename T>
+requires(cxx_is_parsable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_parse_extract(T& value, ::rust::Str text) noexcept -> cxx_parse_status
+{
+  cxx_parse_streambuf streambuf{ text };
+  std::istream is{ &streambuf };
+  try {
+    is >> value;
+  } catch (...) {
+    return cxx_parse_status::bad;
+  }
+  if (is.bad()) {
+    return cxx_parse_status::bad;
+  }
+  if (is.fail()) {
+    return cxx_parse_status::fail;
+  }
+  // NOTE: trailing whitespace is accepted since extraction of the next value would skip it anyway
+  if (streambuf.remaining().find_first_not_of(" \t\n\v\f\r") != std::string_view::npos) {
+    return cxx_parse_status::trailing;
+  }
+  return cxx_parse_status::ok;
+}
+
+// NOTE: `This` is left uninitialized unless the returned status is `ok`
+template<typename T>
+requires(cxx_is_parsable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_parse_new(T* This [[clang::lifetimebound]], ::rust::Str text) noexcept -> std::uint8_t
+{
+  cxx_placement_new(This);
+  auto const status = cxx_parse_extract(*This, text);
+  if (status != cxx_parse_status::ok) {
+    std::destroy_at(This);
+  }
+  return static_cast<std::uint8_t>(status);
+}
+
+template<typename T>
+struct cxx_range_cursor
+{
+  std::ranges::iterator_t<T const> current;
+  std::ranges::sentinel_t<T const> end;
+};
+
+template<typename T>
+requires(cxx_is_iterable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_range_cursor_new(T const& This [[clang::lifetimebound]]) noexcept -> std::unique_ptr<cxx_range_cursor<T>>
 {
-  return rust::String::lossy(std::string{ This.operator std::string_view() });
+  return std::unique_ptr<cxx_range_cursor<T>>(new cxx_range_cursor<T>{ std::ranges::begin(This), std::ranges::end(This) });
+}
+
+template<typename T>
+requires(cxx_is_iterable<T>())
+[[gnu::always_inline]]
+static inline auto
+cxx_range_cursor_next(cxx_range_cursor<T>& cursor) noexcept -> std::ranges::range_value_t<T const> const*
+{
+  if (cursor.current == cursor.end) {
+    return nullptr;
+  }
+  auto const& item = *cursor.current;
+  ++cursor.current;
+  return std::addressof(item);
 }
 
 }; // namespace cxx_auto
@@ -705,11 +1514,46 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   )                                                                                                                    \
   (__VA_ARGS__)
 
+#define CXX_AUTO_PRELUDE_HAS_OPERATOR(NAME, OP)                                                                        \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_operator_##NAME() noexcept -> bool                                              \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_operator_##NAME<Self>();                                                                \
+  }
+
+#define CXX_AUTO_PRELUDE_BINARY_OPERATOR(NAME, OP)                                                                     \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_##NAME<T>())                                       \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T* This [[clang::lifetimebound]], T const& lhs, T const& rhs) noexcept -> void\
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_##NAME(This, lhs, rhs);                                                            \
+  }
+
+#define CXX_AUTO_PRELUDE_UNARY_OPERATOR(NAME, OP)                                                                      \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_##NAME<T>())                                       \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T* This [[clang::lifetimebound]], T const& arg) noexcept -> void              \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_##NAME(This, arg);                                                                 \
+  }
+
+#define CXX_AUTO_PRELUDE_COMPOUND_ASSIGNMENT_OPERATOR(NAME, OP)                                                        \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_##NAME<T>())                                       \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_##NAME(T& This [[clang::lifetimebound]], T const& That) noexcept -> void             \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_##NAME(This, That);                                                                \
+  }
+
 #define CXX_AUTO_PRELUDE(CXX_NAME, ...)                                                                                \
   CXX_AUTO_PRELUDE_TY_CON_DEFINE(__VA_ARGS__)                                                                          \
   CXX_AUTO_PRELUDE_TY_ARGS_DEFINE(__VA_ARGS__)                                                                         \
   CXX_AUTO_PRELUDE_TYPE_DEFINE(__VA_ARGS__)                                                                            \
   using CXX_NAME = Self;                                                                                               \
+  using cxx_range_cursor = ::cxx_auto::cxx_range_cursor<Self>;                                                         \
                                                                                                                        \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto cxx_abi_align() noexcept -> size_t                                                      \
@@ -723,6 +1567,16 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
     return ::cxx_auto::cxx_abi_size<Self>();                                                                           \
   }                                                                                                                    \
                                                                                                                        \
+  template<typename T = Self, size_t N, size_t A>                                                                      \
+  requires(::std::same_as<T, Self>)                                                                                    \
+  [[gnu::always_inline]]                                                                                               \
+  constexpr static inline auto cxx_assert_abi_layout(                                                                  \
+    ::std::array<::std::uint8_t, N> const& size, ::std::array<::std::uint8_t, A> const& align                          \
+  ) noexcept -> void                                                                                                   \
+  {                                                                                                                    \
+    ::cxx_auto::cxx_assert_abi_layout<T>(size, align);                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto cxx_is_default_constructible() noexcept -> bool                                         \
   {                                                                                                                    \
@@ -742,6 +1596,18 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_copy_assignable() noexcept -> bool                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_copy_assignable<Self>();                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_move_assignable() noexcept -> bool                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_move_assignable<Self>();                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto cxx_is_destructible() noexcept -> bool                                                  \
   {                                                                                                                    \
     return ::cxx_auto::cxx_is_destructible<Self>();                                                                    \
@@ -813,6 +1679,69 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
     return ::cxx_auto::cxx_has_operator_three_way_comparison<Self>();                                                  \
   }                                                                                                                    \
                                                                                                                        \
+  CXX_AUTO_OPERATORS(CXX_AUTO_PRELUDE_HAS_OPERATOR, CXX_AUTO_PRELUDE_HAS_OPERATOR, CXX_AUTO_PRELUDE_HAS_OPERATOR)      \
+                                                                                                                       \
+  template<typename From>                                                                                              \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_convertible_from(From const* /*unused*/) noexcept -> bool                        \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_convertible_from<Self, From>();                                                          \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename From>                                                                                              \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_nothrow_convertible_from(From const* /*unused*/) noexcept -> bool                \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_nothrow_convertible_from<Self, From>();                                                  \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename Rhs>                                                                                               \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_operator_equal_with(Rhs const* /*unused*/) noexcept -> bool                     \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_operator_equal<Self, Rhs>();                                                            \
+  }                                                                                                                    \
+                                                                                                                       \
+  /* NOTE: a `rust::Str` right-hand side is compared as a `std::string_view` */                                        \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  static inline auto cxx_has_operator_equal_with(::rust::Str /*unused*/) noexcept -> bool                              \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_operator_equal<Self, ::std::string_view>();                                             \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename Rhs>                                                                                               \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_partially_ordered_with(Rhs const* /*unused*/) noexcept -> bool                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_partially_ordered<Self, Rhs>();                                                          \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  static inline auto cxx_is_partially_ordered_with(::rust::Str /*unused*/) noexcept -> bool                            \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_partially_ordered<Self, ::std::string_view>();                                           \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename Index>                                                                                             \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_operator_subscript_const(Index /*unused*/) noexcept -> bool                     \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_operator_subscript_const<Self, Index>();                                                \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename Index>                                                                                             \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_operator_subscript(Index /*unused*/) noexcept -> bool                           \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_operator_subscript<Self, Index>();                                                      \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_size() noexcept -> bool                                                         \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_size<Self>();                                                                           \
+  }                                                                                                                    \
+                                                                                                                       \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto cxx_is_partially_ordered() noexcept -> bool                                             \
   {                                                                                                                    \
@@ -820,6 +1749,18 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_weakly_ordered() noexcept -> bool                                                \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_weakly_ordered<Self>();                                                                  \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_strongly_ordered() noexcept -> bool                                              \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_strongly_ordered<Self>();                                                                \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto cxx_is_totally_ordered() noexcept -> bool                                               \
   {                                                                                                                    \
     return ::cxx_auto::cxx_is_totally_ordered<Self>();                                                                 \
@@ -844,6 +1785,48 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_has_std_formatter() noexcept -> bool                                                \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_has_std_formatter<Self>();                                                                  \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_string_convertible() noexcept -> bool                                            \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_string_convertible<Self>();                                                              \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_parsable() noexcept -> bool                                                      \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_parsable<Self>();                                                                        \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_iterable() noexcept -> bool                                                      \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_iterable<Self>();                                                                        \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_constructible_from_iterator() noexcept -> bool                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_constructible_from_iterator<Self>();                                                     \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_is_standard_layout() noexcept -> bool                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_is_standard_layout<Self>();                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_enum_is_signed() noexcept -> bool                                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_enum_is_signed<Self>();                                                                     \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
   constexpr static inline auto rust_should_impl_cxx_extern_type_trivial() noexcept -> bool                             \
   {                                                                                                                    \
     return ::cxx_auto::rust_should_impl_cxx_extern_type_trivial<Self>();                                               \
@@ -939,6 +1922,18 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
     return ::cxx_auto::rust_should_impl_display<Self>();                                                               \
   }                                                                                                                    \
                                                                                                                        \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto rust_should_impl_from_iterator() noexcept -> bool                                       \
+  {                                                                                                                    \
+    return ::cxx_auto::rust_should_impl_from_iterator<Self>();                                                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto rust_should_impl_from_str() noexcept -> bool                                            \
+  {                                                                                                                    \
+    return ::cxx_auto::rust_should_impl_from_str<Self>();                                                              \
+  }                                                                                                                    \
+                                                                                                                       \
   template<typename T>                                                                                                 \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_default_constructible<T>())                                  \
   [[gnu::always_inline]]                                                                                               \
@@ -948,6 +1943,102 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_default_constructible<T>())                                  \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_default_unique() noexcept -> ::std::unique_ptr<T>                                             \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_make_unique<T>();                                                                           \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_copy_constructible<T>())                                     \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_copy_unique(T const& that) noexcept -> ::std::unique_ptr<T>                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_make_unique<T>(that);                                                                       \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_move_constructible<T>())                                     \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_move_unique(T* that) noexcept -> ::std::unique_ptr<T>                                         \
+  {                                                                                                                    \
+    /* NOLINTNEXTLINE(hicpp-move-const-arg, performance-move-const-arg) */                                             \
+    return ::cxx_auto::cxx_make_unique<T>(::std::move(*that));                                                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_default_constructible<T>())                                  \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_default_shared() noexcept -> ::std::shared_ptr<T>                                             \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_make_shared<T>();                                                                           \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_copy_constructible<T>())                                     \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_copy_shared(T const& that) noexcept -> ::std::shared_ptr<T>                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_make_shared<T>(that);                                                                       \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_move_constructible<T>())                                     \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_move_shared(T* that) noexcept -> ::std::shared_ptr<T>                                         \
+  {                                                                                                                    \
+    /* NOLINTNEXTLINE(hicpp-move-const-arg, performance-move-const-arg) */                                             \
+    return ::cxx_auto::cxx_make_shared<T>(::std::move(*that));                                                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Item>                                                                                  \
+  requires(                                                                                                            \
+    ::std::same_as<T, Self> and ::cxx_auto::cxx_is_constructible_from_iterator<T>() and                                \
+    ::std::same_as<Item, ::std::ranges::range_value_t<T>>                                                              \
+  )                                                                                                                    \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_from_iter_new(T* This [[clang::lifetimebound]], Item const* first, Item const* last) noexcept \
+    -> void                                                                                                            \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_from_iter_new(This, first, last);                                                           \
+  }                                                                                                                    \
+                                                                                                                       \
+  /* NOTE: arithmetic sources are passed through the bridge by value, all others by reference */                       \
+  template<typename T, typename From>                                                                                  \
+  requires(                                                                                                            \
+    ::std::same_as<T, Self> and ::cxx_auto::cxx_is_convertible_from<T, From>() and not ::std::is_arithmetic_v<From>)   \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_convert_new(T* This [[clang::lifetimebound]], From const& that) noexcept -> bool              \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_convert_new(This, that);                                                                    \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename From>                                                                                  \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_convertible_from<T, From>() and ::std::is_arithmetic_v<From>)\
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_convert_new(T* This [[clang::lifetimebound]], From that) noexcept -> bool                     \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_convert_new(This, that);                                                                    \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_parsable<T>())                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_parse_new(T* This [[clang::lifetimebound]], ::rust::Str text) noexcept -> ::std::uint8_t      \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_parse_new(This, text);                                                                      \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename... Args>                                                                               \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_constructible<T, Args...>())                                 \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_placement_new(T* This [[clang::lifetimebound]], Args... args) noexcept -> void                \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_placement_new(This, ::std::forward<Args>(args)...);                                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_copy_constructible<T>())                                     \
   [[gnu::always_inline]]                                                                                               \
   static inline auto cxx_copy_new(T* This [[clang::lifetimebound]], T const& that [[clang::lifetimebound]]) noexcept   \
@@ -957,6 +2048,14 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_copy_assignable<T>())                                        \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_copy_assign(T& This, T const& that) noexcept -> void                                          \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_copy_assign(This, that);                                                                    \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_move_constructible<T>())                                     \
   [[gnu::always_inline]]                                                                                               \
   static inline auto cxx_move_new(T* This [[clang::lifetimebound]], T* that [[clang::lifetimebound]]) noexcept -> void \
@@ -966,6 +2065,15 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   }                                                                                                                    \
                                                                                                                        \
   template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_move_assignable<T>())                                        \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_move_assign(T& This, T* that) noexcept -> void                                                \
+  {                                                                                                                    \
+    /* NOLINTNEXTLINE(hicpp-move-const-arg, performance-move-const-arg) */                                             \
+    return ::cxx_auto::cxx_move_assign(This, ::std::move(*that));                                                      \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_destructible<T>())                                           \
   [[gnu::always_inline]]                                                                                               \
   static inline auto cxx_destruct(T* This [[clang::lifetimebound]]) noexcept -> void                                   \
@@ -1038,12 +2146,104 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
   [[gnu::always_inline]]                                                                                               \
   static inline auto cxx_operator_three_way_comparison(                                                                \
     T const& This [[clang::lifetimebound]], T const& That [[clang::lifetimebound]]                                     \
-  ) noexcept -> int8_t                                                                                                 \
+  ) noexcept -> ::cxx_auto::cxx_ordering                                                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_three_way_comparison(This, That);                                                  \
+  }                                                                                                                    \
+                                                                                                                       \
+  /* NOTE: arithmetic right-hand sides are passed through the bridge by value, all others by reference */              \
+  template<typename T, typename Rhs>                                                                                   \
+  requires(                                                                                                            \
+    ::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_equal<T, Rhs>() and not ::std::is_arithmetic_v<Rhs>)      \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_equal_with(                                                                          \
+    T const& This [[clang::lifetimebound]], Rhs const& That [[clang::lifetimebound]]                                   \
+  ) noexcept -> bool                                                                                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_equal(This, That);                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Rhs>                                                                                   \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_equal<T, Rhs>() and ::std::is_arithmetic_v<Rhs>)   \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_equal_with(                                                                          \
+    T const& This [[clang::lifetimebound]], Rhs That                                                                   \
+  ) noexcept -> bool                                                                                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_equal(This, That);                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_equal<T, ::std::string_view>())                    \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_equal_with(                                                                          \
+    T const& This [[clang::lifetimebound]], ::rust::Str That                                                           \
+  ) noexcept -> bool                                                                                                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_equal(This, ::std::string_view(That.data(), That.size()));                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Rhs>                                                                                   \
+  requires(                                                                                                            \
+    ::std::same_as<T, Self> and ::cxx_auto::cxx_is_partially_ordered<T, Rhs>() and not ::std::is_arithmetic_v<Rhs>)    \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_three_way_comparison_with(                                                           \
+    T const& This [[clang::lifetimebound]], Rhs const& That [[clang::lifetimebound]]                                   \
+  ) noexcept -> ::cxx_auto::cxx_ordering                                                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_three_way_comparison(This, That);                                                  \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Rhs>                                                                                   \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_partially_ordered<T, Rhs>() and ::std::is_arithmetic_v<Rhs>) \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_three_way_comparison_with(                                                           \
+    T const& This [[clang::lifetimebound]], Rhs That                                                                   \
+  ) noexcept -> ::cxx_auto::cxx_ordering                                                                               \
   {                                                                                                                    \
     return ::cxx_auto::cxx_operator_three_way_comparison(This, That);                                                  \
   }                                                                                                                    \
                                                                                                                        \
   template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_partially_ordered<T, ::std::string_view>())                  \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_three_way_comparison_with(                                                           \
+    T const& This [[clang::lifetimebound]], ::rust::Str That                                                           \
+  ) noexcept -> ::cxx_auto::cxx_ordering                                                                               \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_three_way_comparison(This, ::std::string_view(That.data(), That.size()));          \
+  }                                                                                                                    \
+                                                                                                                       \
+  CXX_AUTO_OPERATORS(                                                                                                  \
+    CXX_AUTO_PRELUDE_BINARY_OPERATOR, CXX_AUTO_PRELUDE_UNARY_OPERATOR, CXX_AUTO_PRELUDE_COMPOUND_ASSIGNMENT_OPERATOR   \
+  )                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Index>                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_subscript_const<T, Index>())                       \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_subscript_const(T const& This [[clang::lifetimebound]], Index index) noexcept        \
+    -> decltype(auto)                                                                                                  \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_subscript_const(This, index);                                                      \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename Index>                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_operator_subscript<T, Index>())                             \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_operator_subscript(T& This [[clang::lifetimebound]], Index index) noexcept -> decltype(auto)  \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_operator_subscript(This, index);                                                            \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_size<T>())                                                  \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_size(T const& This) noexcept -> size_t                                                        \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_size(This);                                                                                 \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_hashable<T>())                                               \
   [[gnu::always_inline]]                                                                                               \
   static inline auto cxx_hash(T const& This [[clang::lifetimebound]]) noexcept -> size_t                               \
@@ -1051,20 +2251,76 @@ cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::String
     return ::cxx_auto::cxx_hash(This);                                                                                 \
   }                                                                                                                    \
                                                                                                                        \
-  template<typename T>                                                                                                 \
+  template<typename T, typename W>                                                                                     \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_debuggable<T>())                                             \
   [[gnu::always_inline]]                                                                                               \
-  static inline auto cxx_debug(T const& This [[clang::lifetimebound]]) noexcept -> rust::string                        \
+  static inline auto cxx_debug(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool                     \
   {                                                                                                                    \
-    return ::cxx_auto::cxx_debug(This);                                                                                \
+    return ::cxx_auto::cxx_debug(This, writer);                                                                        \
   }                                                                                                                    \
                                                                                                                        \
-  template<typename T>                                                                                                 \
+  template<typename T, typename W>                                                                                     \
   requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_displayable<T>())                                            \
   [[gnu::always_inline]]                                                                                               \
-  static inline auto cxx_display(T const& This [[clang::lifetimebound]]) noexcept -> rust::string                      \
+  static inline auto cxx_display(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool                   \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_display(This, writer);                                                                      \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T, typename W>                                                                                     \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_has_std_formatter<T>())                                         \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_display_format(T const& This [[clang::lifetimebound]], ::rust::Str spec, W& writer) noexcept  \
+    -> ::std::uint8_t                                                                                                  \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_display_format(This, spec, writer);                                                         \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_iterable<T>())                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_range_cursor_new(T const& This [[clang::lifetimebound]]) noexcept                             \
+    -> ::std::unique_ptr<cxx_range_cursor>                                                                             \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_range_cursor_new(This);                                                                     \
+  }                                                                                                                    \
+                                                                                                                       \
+  template<typename T>                                                                                                 \
+  requires(::std::same_as<T, Self> and ::cxx_auto::cxx_is_iterable<T>())                                               \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_range_cursor_next(::cxx_auto::cxx_range_cursor<T>& cursor) noexcept                           \
+    -> ::std::ranges::range_value_t<T const> const*                                                                    \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_range_cursor_next(cursor);                                                                  \
+  }
+
+#define CXX_AUTO_ENUMERATOR(NAME)                                                                                      \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_enumerator_##NAME() noexcept -> uint64_t                                            \
+  {                                                                                                                    \
+    return ::cxx_auto::cxx_enumerator_bits(Self::NAME);                                                                \
+  }
+
+#define CXX_AUTO_FIELD(NAME)                                                                                           \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_field_offset_##NAME() noexcept -> size_t                                            \
+  {                                                                                                                    \
+    return offsetof(Self, NAME);                                                                                       \
+  }                                                                                                                    \
+                                                                                                                       \
+  [[nodiscard]] [[gnu::always_inline]] [[gnu::const]]                                                                  \
+  constexpr static inline auto cxx_field_size_##NAME() noexcept -> size_t                                              \
+  {                                                                                                                    \
+    return sizeof(Self::NAME);                                                                                         \
+  }
+
+#define CXX_AUTO_FMT_MEMBER(NAME)                                                                                      \
+  template<typename T, typename W>                                                                                     \
+  requires(::std::same_as<T, Self>)                                                                                    \
+  [[gnu::always_inline]]                                                                                               \
+  static inline auto cxx_fmt_member_##NAME(T const& This [[clang::lifetimebound]], W& writer) noexcept -> bool         \
   {                                                                                                                    \
-    return ::cxx_auto::cxx_display(This);                                                                              \
+    return ::cxx_auto::cxx_fmt_member(This.NAME(), writer);                                                            \
   }
 
 // NOLINTEND(cppcoreguidelines-macro-usage, bugprone-macro-parentheses)
@@ -0,0 +1,44 @@
+/// The result of a C++ three-way comparison, as returned by `cxx_auto::cxx_operator_three_way_comparison`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(i8)]
+pub enum CxxOrdering {
+    /// The left-hand side compares less than the right-hand side.
+    Less = -1,
+    /// Neither side compares less than the other, although they may still be distinguishable under a weak ordering.
+    Equivalent = 0,
+    /// The left-hand side compares greater than the right-hand side.
+    Greater = 1,
+    /// The operands are unordered, e.g., a NaN under `std::partial_ordering`.
+    Unordered = 2,
+}
+
+impl CxxOrdering {
+    /// Converts to the corresponding `Ordering`, or `None` if the operands are unordered.
+    #[must_use]
+    pub fn to_partial_ordering(self) -> Option<core::cmp::Ordering> {
+        match self {
+            Self::Less => Some(core::cmp::Ordering::Less),
+            Self::Equivalent => Some(core::cmp::Ordering::Equal),
+            Self::Greater => Some(core::cmp::Ordering::Greater),
+            Self::Unordered => None,
+        }
+    }
+
+    /// Converts to the corresponding `Ordering`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the operands are unordered, which a type implementing `Ord` must never report.
+    #[must_use]
+    pub fn to_ordering(self) -> core::cmp::Ordering {
+        match self.to_partial_ordering() {
+            Some(ordering) => ordering,
+            None => panic!("cxx-auto: unordered operands in a total order"),
+        }
+    }
+}
+
+unsafe impl cxx::ExternType for CxxOrdering {
+    type Id = cxx::type_id!("cxx_auto::cxx_ordering");
+    type Kind = cxx::kind::Trivial;
+}
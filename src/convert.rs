@@ -0,0 +1,24 @@
+/// The error returned when a potentially throwing C++ conversion threw an exception.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ConvertError;
+
+impl ConvertError {
+    /// Converts the result returned by `cxx_auto::cxx_convert_new`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the conversion threw.
+    pub fn check(converted: bool) -> Result<(), Self> {
+        if converted { Ok(()) } else { Err(Self) }
+    }
+}
+
+impl core::fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("C++ conversion threw an exception")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConvertError {
+}
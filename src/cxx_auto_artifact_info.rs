@@ -3,6 +3,7 @@ use syn::punctuated::Punctuated;
 
 #[allow(clippy::struct_excessive_bools)]
 #[cfg(feature = "alloc")]
+#[derive(Default)]
 pub struct CxxAutoArtifactInfo {
     pub path_components: ::alloc::vec::Vec<&'static str>,
     pub path_descendants: ::alloc::vec::Vec<&'static str>,
@@ -11,6 +12,20 @@ pub struct CxxAutoArtifactInfo {
     pub cxx_name: &'static str,
     pub rust_name: &'static str,
     pub lifetimes: ::indexmap::IndexMap<&'static str, ::alloc::vec::Vec<&'static str>>,
+    pub rust_default_new_visibility: &'static str,
+    pub smart_pointers: bool,
+    pub enum_repr: Option<crate::CxxAutoEnumRepr>,
+    pub enumerators: ::alloc::vec::Vec<(&'static str, u64)>,
+    pub methods: ::alloc::vec::Vec<crate::CxxAutoMethod<'static>>,
+    pub constructors: ::alloc::vec::Vec<crate::CxxAutoConstructor<'static>>,
+    pub fields: ::alloc::vec::Vec<(crate::CxxAutoField<'static>, usize, usize)>,
+    pub conversions: ::alloc::vec::Vec<(crate::CxxAutoConversion<'static>, bool, bool)>,
+    pub comparisons: ::alloc::vec::Vec<(crate::CxxAutoTypeRef<'static>, bool, bool)>,
+    pub samples: ::alloc::vec::Vec<&'static str>,
+    pub range_item: Option<crate::CxxAutoTypeRef<'static>>,
+    pub index: Option<crate::CxxAutoIndex<'static>>,
+    pub debug_fmt: Option<crate::CxxAutoFmtSource<'static>>,
+    pub display_fmt: Option<crate::CxxAutoFmtSource<'static>>,
     pub align: usize,
     pub size: usize,
     pub cxx_has_operator_equal: bool,
@@ -19,6 +34,32 @@ pub struct CxxAutoArtifactInfo {
     pub cxx_has_operator_less_than_or_equal: bool,
     pub cxx_has_operator_greater_than: bool,
     pub cxx_has_operator_greater_than_or_equal: bool,
+    pub cxx_operators: ::alloc::vec::Vec<&'static str>,
+    pub cxx_has_operator_subscript_const: bool,
+    pub cxx_has_operator_subscript: bool,
+    pub cxx_has_size: bool,
+    pub cxx_is_default_constructible: bool,
+    pub cxx_is_copy_constructible: bool,
+    pub cxx_is_move_constructible: bool,
+    pub cxx_is_copy_assignable: bool,
+    pub cxx_is_move_assignable: bool,
+    pub cxx_is_destructible: bool,
+    pub cxx_is_trivially_copyable: bool,
+    pub cxx_is_trivially_movable: bool,
+    pub cxx_is_trivially_destructible: bool,
+    pub cxx_is_partially_ordered: bool,
+    pub cxx_is_weakly_ordered: bool,
+    pub cxx_is_strongly_ordered: bool,
+    pub cxx_is_hashable: bool,
+    pub cxx_is_debuggable: bool,
+    pub cxx_is_displayable: bool,
+    pub cxx_is_string_convertible: bool,
+    pub cxx_has_std_formatter: bool,
+    pub cxx_is_standard_layout: bool,
+    pub cxx_is_iterable: bool,
+    pub cxx_is_constructible_from_iterator: bool,
+    pub cxx_is_parsable: bool,
+    pub overrides: crate::CxxAutoOverrides,
     pub is_rust_cxx_extern_type_trivial: bool,
     pub is_rust_unpin: bool,
     pub is_rust_send: bool,
@@ -35,10 +76,13 @@ pub struct CxxAutoArtifactInfo {
     pub is_rust_partial_ord: bool,
     pub is_rust_ord: bool,
     pub is_rust_hash: bool,
+    pub is_rust_from_iterator: bool,
+    pub is_rust_from_str: bool,
 }
 
 #[cfg(feature = "alloc")]
 impl CxxAutoArtifactInfo {
+    #[allow(clippy::too_many_lines)]
     #[must_use]
     pub fn emit_file(&self, auto_out_dir: &::std::path::Path) -> syn::File {
         let span = Span::call_site();
@@ -62,36 +106,122 @@ impl CxxAutoArtifactInfo {
                 }
             })
             .collect::<alloc::vec::Vec<syn::Item>>();
+        if let Some(repr) = self.enum_repr {
+            let repr = &repr.emit_ident();
+            let item_enum = emit_enum(self, ident, repr);
+            let item_const_assert_abi_layout = emit_const_assert_abi_layout(self, ident, align, size);
+            let item_impl_cxx_extern_type = emit_impl_cxx_extern_type(self, ident, generics_binder, generics);
+            let items_impl_enum_conversions = emit_impls_enum_conversions(self, ident, repr);
+            let item_mod_cxx_bridge = emit_enum_item_mod_cxx_bridge(self, ident);
+            let item_info_test_module = emit_enum_info_test_module(self, ident, repr, align, size);
+            return syn::parse_quote! {
+                #(#items_path_descendants)*
+                #item_enum
+                #item_const_assert_abi_layout
+                #item_impl_cxx_extern_type
+                #(#items_impl_enum_conversions)*
+                #item_mod_cxx_bridge
+                #item_info_test_module
+            };
+        }
+        let item_use_cxx_vocabulary = emit_item_use_cxx_vocabulary(self);
         let item_struct = emit_struct(self, align, size, ident, generics_binder, generics);
+        let item_const_assert_abi_layout = emit_const_assert_abi_layout(self, ident, align, size);
+        let items_const_assert_field_layouts = emit_const_asserts_field_layouts(self);
         let item_impl_cxx_extern_type = emit_impl_cxx_extern_type(self, ident, generics_binder, generics);
+        let item_impl_send = emit_impl_send(self, ident, generics_binder, generics);
+        let item_impl_sync = emit_impl_sync(self, ident, generics_binder, generics);
         let item_impl_drop = emit_impl_drop(self, ident, generics_binder, generics);
         let item_impl_debug = emit_impl_debug(self, ident, generics_binder, generics);
+        let item_impl_default_new = emit_impl_default_new(self, ident, generics_binder, generics);
         let item_impl_default = emit_impl_default(self, ident, generics_binder, generics);
         let item_impl_display = emit_impl_display(self, ident, generics_binder, generics);
+        let item_struct_cxx_fmt_writer = emit_struct_cxx_fmt_writer(self);
+        let item_impl_cxx_fmt_writer = emit_impl_cxx_fmt_writer(self);
+        let item_impl_clone = emit_impl_clone(self, ident, generics_binder, generics);
         let item_impl_moveit_copy_new = emit_impl_moveit_copy_new(self, ident, generics_binder, generics);
+        let item_impl_assignments = emit_impl_assignments(self, ident, generics_binder, generics);
+        let item_impl_box_constructors = emit_impl_box_constructors(self, ident, generics_binder, generics);
+        let item_impl_unique_constructors = emit_impl_unique_constructors(self, ident, generics_binder, generics);
+        let item_impl_shared_constructors = emit_impl_shared_constructors(self, ident, generics_binder, generics);
         let item_impl_moveit_move_new = emit_impl_moveit_move_new(self, ident, generics_binder, generics);
         let item_impl_partial_eq = emit_impl_partial_eq(self, ident, generics_binder, generics);
         let item_impl_eq = emit_impl_eq(self, ident, generics_binder, generics);
         let item_impl_partial_ord = emit_impl_partial_ord(self, ident, generics_binder, generics);
         let item_impl_ord = emit_impl_ord(self, ident, generics_binder, generics);
+        let items_impl_comparisons = emit_impls_comparisons(self, ident, generics_binder, generics);
         let item_impl_hash = emit_impl_hash(self, ident, generics_binder, generics);
+        let item_impl_constructors = emit_impl_constructors(self, ident, generics_binder, generics);
+        let item_impl_conversion_constructors =
+            emit_impl_conversion_constructors(self, ident, generics_binder, generics);
+        let items_impl_conversions = emit_impls_conversions(self, ident, generics_binder, generics);
+        let item_impl_fields = emit_impl_fields(self, ident, generics_binder, generics);
+        let item_impl_methods = emit_impl_methods(self, ident, generics_binder, generics);
+        let items_operation_new = emit_items_operation_new(self, ident, generics_binder, generics);
+        let items_impl_operators = emit_impls_operators(self, ident, generics_binder, generics);
+        let item_struct_range_iter = emit_struct_range_iter(self, ident, generics_binder, generics);
+        let item_impl_range_iter = emit_impl_range_iter(self, ident, generics_binder, generics);
+        let item_impl_range_iter_iterator = emit_impl_range_iter_iterator(self, ident, generics_binder, generics);
+        let item_impl_range_iter_fused_iterator =
+            emit_impl_range_iter_fused_iterator(self, ident, generics_binder, generics);
+        let item_impl_into_iterator = emit_impl_into_iterator(self, ident, generics_binder, generics);
+        let item_impl_from_iter_new = emit_impl_from_iter_new(self, ident, generics_binder, generics);
+        let item_impl_from_iterator = emit_impl_from_iterator(self, ident, generics_binder, generics);
+        let item_impl_parse_new = emit_impl_parse_new(self, ident, generics_binder, generics);
+        let item_impl_from_str = emit_impl_from_str(self, ident, generics_binder, generics);
+        let item_impl_index = emit_impl_index(self, ident, generics_binder, generics);
+        let item_impl_index_mut = emit_impl_index_mut(self, ident, generics_binder, generics);
+        let item_impl_index_pin_mut = emit_impl_index_pin_mut(self, ident, generics_binder, generics);
         let item_mod_cxx_bridge = emit_item_mod_cxx_bridge(self, ident, generics);
         let item_info_test_module = emit_info_test_module(self, ident, align, size);
         syn::parse_quote! {
             #(#items_path_descendants)*
+            #item_use_cxx_vocabulary
             #item_struct
+            #item_const_assert_abi_layout
+            #(#items_const_assert_field_layouts)*
             #item_impl_cxx_extern_type
+            #item_impl_send
+            #item_impl_sync
             #item_impl_drop
+            #item_impl_default_new
             #item_impl_default
+            #item_impl_clone
             #item_impl_moveit_copy_new
             #item_impl_moveit_move_new
+            #item_impl_assignments
+            #item_impl_box_constructors
+            #item_impl_unique_constructors
+            #item_impl_shared_constructors
             #item_impl_partial_eq
             #item_impl_eq
             #item_impl_partial_ord
             #item_impl_ord
+            #(#items_impl_comparisons)*
             #item_impl_hash
             #item_impl_debug
             #item_impl_display
+            #item_struct_cxx_fmt_writer
+            #item_impl_cxx_fmt_writer
+            #item_impl_constructors
+            #item_impl_conversion_constructors
+            #(#items_impl_conversions)*
+            #item_impl_fields
+            #item_impl_methods
+            #(#items_operation_new)*
+            #(#items_impl_operators)*
+            #item_struct_range_iter
+            #item_impl_range_iter
+            #item_impl_range_iter_iterator
+            #item_impl_range_iter_fused_iterator
+            #item_impl_into_iterator
+            #item_impl_from_iter_new
+            #item_impl_from_iterator
+            #item_impl_parse_new
+            #item_impl_from_str
+            #item_impl_index
+            #item_impl_index_mut
+            #item_impl_index_pin_mut
             #item_mod_cxx_bridge
             #item_info_test_module
         }
@@ -142,9 +272,189 @@ impl CxxAutoArtifactInfo {
         Ok(())
     }
 
+    /// # Errors
+    ///
+    /// Will return `Err` if a trait decision (detected or overridden) is unsound for the C++ type, e.g., `Copy` for a
+    /// type which is not trivially copyable.
+    pub fn check_trait_decisions(&self) -> crate::BoxResult<()> {
+        let (is_debug_supported, debug_reason) = debug_fmt(self).is_supported(self);
+        let (is_display_supported, display_reason) = display_fmt(self).is_supported(self);
+        let checks = [
+            (self.is_rust_unpin, "Unpin", self.cxx_is_trivially_movable, "is not trivially movable"),
+            (self.is_rust_copy, "Copy", self.cxx_is_trivially_copyable, "is not trivially copyable"),
+            (self.is_rust_copy, "Copy", self.cxx_is_trivially_movable, "is not trivially movable"),
+            (self.is_rust_copy, "Copy", !self.is_rust_drop, "requires `Drop`"),
+            (self.is_rust_drop, "Drop", self.cxx_is_destructible, "is not destructible"),
+            (self.is_rust_default, "Default", self.cxx_is_default_constructible, "is not default constructible"),
+            (self.is_rust_copy_new, "CopyNew", self.cxx_is_copy_constructible, "is not copy constructible"),
+            (self.is_rust_move_new, "MoveNew", self.cxx_is_move_constructible, "is not move constructible"),
+            (self.is_rust_partial_eq, "PartialEq", self.cxx_has_operator_equal, "has no `operator==`"),
+            (self.is_rust_eq, "Eq", self.is_rust_partial_eq, "does not implement `PartialEq`"),
+            (self.is_rust_partial_ord, "PartialOrd", self.cxx_is_partially_ordered, "is not partially ordered"),
+            (self.is_rust_ord, "Ord", self.is_rust_partial_ord, "does not implement `PartialOrd`"),
+            (self.is_rust_ord, "Ord", self.is_rust_eq, "does not implement `Eq`"),
+            (self.is_rust_ord, "Ord", self.cxx_is_weakly_ordered, "is only partially ordered"),
+            (self.is_rust_hash, "Hash", self.cxx_is_hashable, "is not hashable"),
+            (self.is_rust_debug, "Debug", is_debug_supported, debug_reason),
+            (self.is_rust_display, "Display", is_display_supported, display_reason),
+            (
+                self.range_item.is_some(),
+                "IntoIterator",
+                self.cxx_is_iterable,
+                "is not a range yielding references to its elements",
+            ),
+            (
+                self.is_rust_from_iterator,
+                "FromIterator",
+                self.cxx_is_constructible_from_iterator,
+                "is not constructible from an iterator pair",
+            ),
+            (
+                self.overrides.from_iterator == Some(true),
+                "FromIterator",
+                self.range_item.is_some(),
+                "has no `range_item`",
+            ),
+            (
+                self.is_rust_from_str,
+                "FromStr",
+                self.cxx_is_parsable,
+                "has no `operator>>` or is not default constructible",
+            ),
+            (
+                self.is_rust_from_str,
+                "FromStr",
+                self.is_rust_move_new,
+                "does not implement `MoveNew`, which `parse_new` requires",
+            ),
+            (
+                self.index.is_some(),
+                "Index",
+                self.cxx_has_operator_subscript_const,
+                "has no `operator[] const` for the declared index type",
+            ),
+            (
+                self.index.is_some(),
+                "Index",
+                self.cxx_has_size,
+                "has no `size()` to bounds check the index against",
+            ),
+        ];
+        for (is_rust_impl, rust_trait, is_sound, reason) in checks {
+            if is_rust_impl && !is_sound {
+                let cxx_namespace = self.cxx_namespace;
+                let cxx_name = self.cxx_name;
+                return Err(::alloc::format!(
+                    "cxx-auto: cannot implement `{rust_trait}` for `{cxx_namespace}::{cxx_name}`: the type {reason}"
+                )
+                .into());
+            }
+        }
+        for (conversion, is_convertible, _) in &self.conversions {
+            if !is_convertible {
+                let cxx_namespace = self.cxx_namespace;
+                let cxx_name = self.cxx_name;
+                let from = conversion.from.rust_path;
+                return Err(::alloc::format!(
+                    "cxx-auto: cannot convert `{from}` into `{cxx_namespace}::{cxx_name}`: the type has no implicit \
+                     conversion from it"
+                )
+                .into());
+            }
+        }
+        for (comparison, cxx_has_operator_equal, _) in &self.comparisons {
+            if !cxx_has_operator_equal {
+                let cxx_namespace = self.cxx_namespace;
+                let cxx_name = self.cxx_name;
+                let rhs = comparison.rust_path;
+                return Err(::alloc::format!(
+                    "cxx-auto: cannot compare `{cxx_namespace}::{cxx_name}` with `{rhs}`: the type has no `operator==` \
+                     for it"
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if fields are declared for a type which is not standard-layout, or if a field lies outside
+    /// of the type or overlaps another field.
+    pub fn check_field_layouts(&self) -> crate::BoxResult<()> {
+        let cxx_namespace = self.cxx_namespace;
+        let cxx_name = self.cxx_name;
+        if !self.fields.is_empty() && !self.cxx_is_standard_layout {
+            return Err(::alloc::format!(
+                "cxx-auto: cannot bind fields of `{cxx_namespace}::{cxx_name}`: the type is not standard-layout"
+            )
+            .into());
+        }
+        let mut cursor = (0, None);
+        for (field, offset, size) in sorted_fields(self) {
+            let name = field.name;
+            if offset + size > self.size {
+                return Err(::alloc::format!(
+                    "cxx-auto: cannot bind field `{name}` of `{cxx_namespace}::{cxx_name}`: the field lies outside of \
+                     the type"
+                )
+                .into());
+            }
+            if let (end, Some(previous)) = cursor {
+                if offset < end {
+                    return Err(::alloc::format!(
+                        "cxx-auto: cannot bind field `{name}` of `{cxx_namespace}::{cxx_name}`: the field overlaps \
+                         field `{previous}`"
+                    )
+                    .into());
+                }
+            }
+            cursor = (offset + size, Some(name));
+        }
+        Ok(())
+    }
+
+    // NOTE: declared methods, constructors, conversions, and field accessors share the inherent namespace of the
+    // type with the generated methods, so collisions are detected on the emitted impls
+    fn check_inherent_methods(&self, file: &syn::File) -> crate::BoxResult<()> {
+        let cxx_namespace = self.cxx_namespace;
+        let cxx_name = self.cxx_name;
+        let mut names = ::alloc::collections::BTreeSet::new();
+        let items_impl_inherent = file.items.iter().filter_map(|item| match item {
+            syn::Item::Impl(item_impl) if item_impl.trait_.is_none() => Some(item_impl),
+            _ => None,
+        });
+        for item_impl in items_impl_inherent {
+            let syn::Type::Path(type_path) = &*item_impl.self_ty else {
+                continue;
+            };
+            match type_path.path.segments.last() {
+                Some(segment) if segment.ident == self.rust_name => {},
+                _ => continue,
+            }
+            for impl_item in &item_impl.items {
+                let syn::ImplItem::Fn(impl_item_fn) = impl_item else {
+                    continue;
+                };
+                let name = &impl_item_fn.sig.ident;
+                if !names.insert(name) {
+                    return Err(::alloc::format!(
+                        "cxx-auto: cannot generate `{cxx_namespace}::{cxx_name}`: more than one inherent method is \
+                         named `{name}`"
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// # Errors
     ///
     /// Will return `Err` under the following circumstances:
+    /// - a trait decision is unsound for the C++ type (see [`CxxAutoArtifactInfo::check_trait_decisions`])
+    /// - a field layout is invalid for the C++ type (see [`CxxAutoArtifactInfo::check_field_layouts`])
+    /// - more than one inherent method of the generated module has the same name
     /// - failure to create the output parent directory for the generated module
     /// - failure to run `rustfmt` on the generated module
     /// - failure to write the generated module to disk
@@ -152,12 +462,15 @@ impl CxxAutoArtifactInfo {
     pub fn write_module_for_file(&self, auto_out_dir_root: &::std::path::Path) -> crate::BoxResult<()> {
         use quote::ToTokens;
         use rust_format::Formatter;
+        self.check_trait_decisions()?;
+        self.check_field_layouts()?;
         let auto_out_dir = auto_out_dir_root.join(std::path::PathBuf::from_iter(&self.path_components));
         if let Some(parent) = auto_out_dir.parent() {
             std::fs::create_dir_all(parent)?;
         }
         let path = auto_out_dir.with_extension("rs");
         let file = self.emit_file(&auto_out_dir);
+        self.check_inherent_methods(&file)?;
         let tokens = file.to_token_stream();
         let contents = rust_format::RustFmt::default().format_tokens(tokens)?;
         std::fs::write(path, contents)?;
@@ -165,6 +478,22 @@ impl CxxAutoArtifactInfo {
     }
 }
 
+// NOTE: user-declared signatures are written in `cxx` bridge syntax, which requires the unqualified names
+#[cfg(feature = "alloc")]
+fn emit_item_use_cxx_vocabulary(info: &CxxAutoArtifactInfo) -> Option<syn::ItemUse> {
+    if info.methods.is_empty() && info.constructors.is_empty() && info.fields.is_empty() {
+        None
+    } else {
+        Some(syn::parse_quote! {
+            #[allow(unused_imports)]
+            use ::{
+                core::pin::Pin,
+                cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, WeakPtr},
+            };
+        })
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn emit_struct(
     info: &CxxAutoArtifactInfo,
@@ -175,21 +504,24 @@ fn emit_struct(
     generics: &syn::Generics,
 ) -> syn::ItemStruct {
     let attribute = emit_derive_attribute(info);
-    let field_layout = field_layout(size);
-    let field_neither_send_nor_sync = field_neither_send_nor_sync(info);
+    let fields_layout = if has_typed_fields(info) {
+        fields_typed_layout(info)
+    } else {
+        ::alloc::vec![field_layout(size)]
+    };
+    let field_send_sync_marker = field_send_sync_marker(info);
     let field_pinned = field_pinned(info);
     let field_lifetimes = field_lifetimes(generics);
     let fields = syn::FieldsNamed {
         brace_token: syn::token::Brace::default(),
-        named: ::alloc::vec![
-            Some(field_layout),
-            field_neither_send_nor_sync,
-            field_pinned,
-            field_lifetimes,
-        ]
-        .into_iter()
-        .flatten()
-        .collect::<Punctuated<syn::Field, syn::Token![,]>>(),
+        named: fields_layout
+            .into_iter()
+            .chain(
+                [field_send_sync_marker, field_pinned, field_lifetimes]
+                    .into_iter()
+                    .flatten(),
+            )
+            .collect::<Punctuated<syn::Field, syn::Token![,]>>(),
     };
     syn::parse_quote! {
         #attribute
@@ -198,6 +530,252 @@ fn emit_struct(
     }
 }
 
+#[cfg(feature = "alloc")]
+fn emit_enum_discriminant(info: &CxxAutoArtifactInfo, bits: u64) -> syn::Expr {
+    if info.enum_repr.is_some_and(crate::CxxAutoEnumRepr::is_signed) {
+        #[allow(clippy::cast_possible_wrap)]
+        let value = bits as i64;
+        let literal = proc_macro2::Literal::u64_unsuffixed(value.unsigned_abs());
+        if value < 0 {
+            syn::parse_quote!(-#literal)
+        } else {
+            syn::parse_quote!(#literal)
+        }
+    } else {
+        let literal = proc_macro2::Literal::u64_unsuffixed(bits);
+        syn::parse_quote!(#literal)
+    }
+}
+
+// NOTE: C++ enumerators may alias a previous value, but Rust discriminants must be unique, so only the first
+// enumerator for each value becomes a variant and the remaining ones become associated constants
+#[cfg(feature = "alloc")]
+type EnumVariants = ::alloc::vec::Vec<(&'static str, u64)>;
+
+#[cfg(feature = "alloc")]
+type EnumAliases = ::alloc::vec::Vec<(&'static str, &'static str)>;
+
+#[cfg(feature = "alloc")]
+fn enum_variants_and_aliases(info: &CxxAutoArtifactInfo) -> (EnumVariants, EnumAliases) {
+    let mut variants = EnumVariants::new();
+    let mut aliases = EnumAliases::new();
+    for &(name, bits) in &info.enumerators {
+        if let Some(&(variant, _)) = variants.iter().find(|(_, value)| *value == bits) {
+            aliases.push((name, variant));
+        } else {
+            variants.push((name, bits));
+        }
+    }
+    (variants, aliases)
+}
+
+#[cfg(feature = "alloc")]
+fn emit_enum(info: &CxxAutoArtifactInfo, ident: &syn::Ident, repr: &syn::Ident) -> syn::ItemEnum {
+    let span = Span::call_site();
+    let (variants, _) = enum_variants_and_aliases(info);
+    let variants = variants.iter().map(|&(name, bits)| -> syn::Variant {
+        let name = syn::Ident::new(name, span);
+        let discriminant = emit_enum_discriminant(info, bits);
+        syn::parse_quote!(#name = #discriminant)
+    });
+    syn::parse_quote! {
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[repr(#repr)]
+        pub enum #ident {
+            #(#variants),*
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impls_enum_conversions(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    repr: &syn::Ident,
+) -> ::alloc::vec::Vec<syn::ItemImpl> {
+    let span = Span::call_site();
+    let (variants, aliases) = enum_variants_and_aliases(info);
+    let arms = variants.iter().map(|&(name, bits)| -> syn::Arm {
+        let name = syn::Ident::new(name, span);
+        let discriminant = emit_enum_discriminant(info, bits);
+        syn::parse_quote!(#discriminant => Ok(Self::#name),)
+    });
+    let item_impl_aliases: Option<syn::ItemImpl> = if aliases.is_empty() {
+        None
+    } else {
+        let aliases = aliases.iter().map(|&(alias, variant)| -> syn::ImplItemConst {
+            let alias = syn::Ident::new(alias, span);
+            let variant = syn::Ident::new(variant, span);
+            syn::parse_quote! {
+                #[allow(non_upper_case_globals)]
+                pub const #alias: Self = Self::#variant;
+            }
+        });
+        Some(syn::parse_quote! {
+            impl #ident {
+                #(#aliases)*
+            }
+        })
+    };
+    item_impl_aliases.into_iter().chain([
+        syn::parse_quote! {
+            impl ::core::convert::TryFrom<#repr> for #ident {
+                type Error = #repr;
+                #[inline]
+                fn try_from(value: #repr) -> Result<Self, Self::Error> {
+                    match value {
+                        #(#arms)*
+                        _ => Err(value),
+                    }
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl ::core::convert::From<#ident> for #repr {
+                #[inline]
+                fn from(value: #ident) -> Self {
+                    value as #repr
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl ::core::cmp::PartialOrd for #ident {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+        },
+        syn::parse_quote! {
+            impl ::core::cmp::Ord for #ident {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    (*self as #repr).cmp(&(*other as #repr))
+                }
+            }
+        },
+    ])
+    .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn emit_enum_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident) -> syn::ItemMod {
+    let align = proc_macro2::Literal::usize_unsuffixed(info.align);
+    let size = proc_macro2::Literal::usize_unsuffixed(info.size);
+    let cxx_include = &info.cxx_include;
+    let cxx_namespace = &info.cxx_namespace;
+    let cxx_name = &info.cxx_name;
+    syn::parse_quote! {
+        #[cxx::bridge]
+        pub(crate) mod ffi {
+            #[namespace = #cxx_namespace]
+            unsafe extern "C++" {
+                include!(#cxx_include);
+
+                #[cxx_name = #cxx_name]
+                #[allow(unused)]
+                type #ident = super :: #ident;
+
+                #[allow(unused)]
+                fn cxx_assert_abi_layout(size: &[u8; #size], align: &[u8; #align]);
+            }
+        }
+    }
+}
+
+// NOTE: the bridge checks the same layout on the C++ side, by deducing it from `cxx_assert_abi_layout`
+#[cfg(feature = "alloc")]
+fn emit_const_assert_abi_layout(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    align: &proc_macro2::Literal,
+    size: &proc_macro2::Literal,
+) -> syn::ItemConst {
+    let (_, generics) = {
+        let all_static = true;
+        &emit_generics(info, all_static)
+    };
+    syn::parse_quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#ident #generics>() == #size
+                && ::core::mem::align_of::<#ident #generics>() == #align,
+            "cxx-auto: the C++ layout has changed since the bindings were generated"
+        );
+    }
+}
+
+// NOTE: the field accessors are safe, so a mistyped field must fail every build rather than only the tests
+#[cfg(feature = "alloc")]
+fn emit_const_asserts_field_layouts(info: &CxxAutoArtifactInfo) -> ::alloc::vec::Vec<syn::ItemConst> {
+    info.fields
+        .iter()
+        .map(|(field, offset, size)| -> syn::ItemConst {
+            let name = field.name;
+            let ty = emit_field_type_all_static(info, field);
+            let offset = proc_macro2::Literal::usize_unsuffixed(*offset);
+            let size = proc_macro2::Literal::usize_unsuffixed(*size);
+            let message = ::alloc::format!("cxx-auto: the type of field `{name}` does not match its C++ layout");
+            syn::parse_quote! {
+                const _: () = ::core::assert!(
+                    ::core::mem::size_of::<#ty>() == #size && #offset % ::core::mem::align_of::<#ty>() == 0,
+                    #message
+                );
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn emit_enum_info_test_module(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    repr: &syn::Ident,
+    align: &proc_macro2::Literal,
+    size: &proc_macro2::Literal,
+) -> syn::ItemMod {
+    let span = Span::call_site();
+    let discriminants = info.enumerators.iter().map(|&(name, bits)| -> syn::Stmt {
+        let name = syn::Ident::new(name, span);
+        let discriminant = emit_enum_discriminant(info, bits);
+        syn::parse_quote! {
+            ::core::assert_eq!(#ident::#name as #repr, #discriminant);
+        }
+    });
+    let conversions = info.enumerators.iter().map(|&(name, bits)| -> syn::Stmt {
+        let name = syn::Ident::new(name, span);
+        let discriminant = emit_enum_discriminant(info, bits);
+        syn::parse_quote! {
+            ::core::assert_eq!(#ident::try_from(#discriminant as #repr), Ok(#ident::#name));
+        }
+    });
+    syn::parse_quote! {
+        #[cfg(test)]
+        mod info {
+            use super::*;
+            mod test {
+                use super::*;
+                #[test]
+                fn cxx_abi_align() {
+                    ::core::assert_eq!(::core::mem::align_of::<#ident>(), #align)
+                }
+                #[test]
+                fn cxx_abi_size() {
+                    ::core::assert_eq!(::core::mem::size_of::<#ident>(), #size)
+                }
+                #[test]
+                fn cxx_enumerator_discriminants() {
+                    #(#discriminants)*
+                }
+                #[test]
+                fn cxx_enumerator_conversions() {
+                    #(#conversions)*
+                }
+                ::static_assertions::assert_impl_all!(#ident: ::core::marker::Copy, ::core::marker::Unpin);
+            }
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn emit_derive_attribute(info: &CxxAutoArtifactInfo) -> Option<syn::Attribute> {
     if info.is_rust_copy {
@@ -267,6 +845,38 @@ fn emit_generics(info: &CxxAutoArtifactInfo, all_static: bool) -> (syn::Generics
     )
 }
 
+// NOTE: field types may name the lifetimes of the type, which are only in scope within its impls, so items outside of
+// them refer to the field types with every such lifetime instantiated as `'static`, like `emit_generics(info, true)`
+#[cfg(feature = "alloc")]
+fn emit_field_type_all_static(info: &CxxAutoArtifactInfo, field: &crate::CxxAutoField) -> syn::Type {
+    fn substitute(info: &CxxAutoArtifactInfo, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let mut is_lifetime = false;
+        tokens
+            .into_iter()
+            .map(|token| {
+                let token = match token {
+                    proc_macro2::TokenTree::Group(group) => {
+                        let stream = substitute(info, group.stream());
+                        let mut substituted = proc_macro2::Group::new(group.delimiter(), stream);
+                        substituted.set_span(group.span());
+                        proc_macro2::TokenTree::Group(substituted)
+                    },
+                    proc_macro2::TokenTree::Ident(ident)
+                        if is_lifetime && info.lifetimes.keys().any(|&name| ident == name) =>
+                    {
+                        proc_macro2::TokenTree::Ident(syn::Ident::new("static", ident.span()))
+                    },
+                    token => token,
+                };
+                is_lifetime = matches!(&token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'');
+                token
+            })
+            .collect()
+    }
+    let tokens = substitute(info, quote::ToTokens::into_token_stream(field.emit_type()));
+    syn::parse_quote!(#tokens)
+}
+
 #[cfg(feature = "alloc")]
 fn emit_impl_cxx_extern_type(
     info: &CxxAutoArtifactInfo,
@@ -288,6 +898,38 @@ fn emit_impl_cxx_extern_type(
     }
 }
 
+#[cfg(feature = "alloc")]
+fn emit_impl_send(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.is_rust_send {
+        Some(syn::parse_quote! {
+            unsafe impl #generics_binder ::core::marker::Send for #ident #generics {}
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_sync(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.is_rust_sync {
+        Some(syn::parse_quote! {
+            unsafe impl #generics_binder ::core::marker::Sync for #ident #generics {}
+        })
+    } else {
+        None
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn emit_impl_drop(
     info: &CxxAutoArtifactInfo,
@@ -320,38 +962,104 @@ fn emit_impl_debug(
     generics: &syn::Generics,
 ) -> syn::ItemImpl {
     if info.is_rust_debug {
+        let debug = emit_fmt_call(debug_fmt(info), false);
         syn::parse_quote! {
             impl #generics_binder ::core::fmt::Debug for #ident #generics {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    let string = self::ffi::cxx_debug(self);
-                    write!(f, "{string}")
+                    #debug
                 }
             }
         }
     } else {
         let name = info.rust_name;
+        let size = proc_macro2::Literal::usize_unsuffixed(info.size);
+        let align = proc_macro2::Literal::usize_unsuffixed(info.align);
+        let fields = info.fields.iter().map(|(field, ..)| -> syn::Expr {
+            let rust_name = field.rust_name();
+            let field = field.emit_ident();
+            if has_typed_fields(info) {
+                syn::parse_quote!(debug.field(#rust_name, &self.#field))
+            } else {
+                syn::parse_quote!(debug.field(#rust_name, self.#field()))
+            }
+        });
+        // NOTE: the layout entries are named after the C++ operators so that they cannot collide with a field name
+        let finish: syn::Expr = if info.fields.iter().map(|&(_, _, size)| size).sum::<usize>() < info.size {
+            syn::parse_quote!(debug.finish_non_exhaustive())
+        } else {
+            syn::parse_quote!(debug.finish())
+        };
         syn::parse_quote! {
             impl #generics_binder ::core::fmt::Debug for #ident #generics {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    f.debug_struct(#name).finish()
+                    let mut debug = f.debug_struct(#name);
+                    #(#fields;)*
+                    debug.field("sizeof", &#size);
+                    debug.field("alignof", &#align);
+                    #finish
                 }
             }
         }
     }
 }
 
+// NOTE: `Debug` defaults to `operator<<`, while `Display` prefers a `std::formatter` since it honors the format spec
 #[cfg(feature = "alloc")]
-fn emit_impl_default(
+fn debug_fmt(info: &CxxAutoArtifactInfo) -> crate::CxxAutoFmtSource<'static> {
+    info.debug_fmt.unwrap_or(crate::CxxAutoFmtSource::Ostream)
+}
+
+#[cfg(feature = "alloc")]
+fn display_fmt(info: &CxxAutoArtifactInfo) -> crate::CxxAutoFmtSource<'static> {
+    info.display_fmt.unwrap_or(if info.cxx_has_std_formatter {
+        crate::CxxAutoFmtSource::Formatter
+    } else {
+        crate::CxxAutoFmtSource::ToString
+    })
+}
+
+// NOTE: only `Display` passes the Rust format spec through, since `Debug` flags (e.g., `{:#?}`) have no C++ equivalent;
+// a spec rejected by the `std::formatter` falls back to `{}`, which is then padded on the Rust side
+#[cfg(feature = "alloc")]
+fn emit_fmt_call(fmt: crate::CxxAutoFmtSource, is_spec_passthrough: bool) -> syn::Expr {
+    let cxx_fmt = fmt.emit_cxx_ident();
+    match fmt {
+        crate::CxxAutoFmtSource::Formatter if is_spec_passthrough => syn::parse_quote! {
+            ::cxx_auto::fmt::write_std_format(f, |spec, f| {
+                self::ffi::#cxx_fmt(self, spec, &mut CxxFmtWriter(f))
+            })
+        },
+        crate::CxxAutoFmtSource::Formatter => syn::parse_quote! {
+            ::cxx_auto::fmt::check_std_format(self::ffi::#cxx_fmt(self, "{}", &mut CxxFmtWriter(f)))
+        },
+        _ => syn::parse_quote! {
+            if self::ffi::#cxx_fmt(self, &mut CxxFmtWriter(f)) {
+                Ok(())
+            } else {
+                Err(::core::fmt::Error)
+            }
+        },
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_visibility(visibility: &str) -> syn::Visibility {
+    syn::parse_str(visibility).unwrap_or_else(|err| panic!("cxx-auto: invalid Rust visibility `{visibility}`: {err}"))
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_default_new(
     info: &CxxAutoArtifactInfo,
     ident: &syn::Ident,
     generics_binder: &syn::Generics,
     generics: &syn::Generics,
 ) -> Option<syn::ItemImpl> {
     if info.is_rust_default {
+        let visibility = emit_visibility(info.rust_default_new_visibility);
         Some(syn::parse_quote! {
             impl #generics_binder #ident #generics {
                 #[inline]
-                pub(crate) fn default_new() -> impl ::moveref::New<Output = #ident #generics> {
+                #visibility fn default_new() -> impl ::moveref::New<Output = #ident #generics> {
                     unsafe {
                         ::moveref::new::by_raw(move |this| {
                             let this = this.get_unchecked_mut().as_mut_ptr();
@@ -366,19 +1074,24 @@ fn emit_impl_default(
     }
 }
 
+// NOTE: types that Rust cannot move are only default constructed in place through `default_new`
 #[cfg(feature = "alloc")]
-fn emit_impl_display(
+fn emit_impl_default(
     info: &CxxAutoArtifactInfo,
     ident: &syn::Ident,
     generics_binder: &syn::Generics,
     generics: &syn::Generics,
 ) -> Option<syn::ItemImpl> {
-    if info.is_rust_display {
+    if info.is_rust_default && info.is_rust_unpin {
         Some(syn::parse_quote! {
-            impl #generics_binder ::core::fmt::Display for #ident #generics {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    let string = self::ffi::cxx_display(self);
-                    write!(f, "{string}")
+            impl #generics_binder ::core::default::Default for #ident #generics {
+                #[inline]
+                fn default() -> Self {
+                    let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                    unsafe {
+                        self::ffi::cxx_default_new(this.as_mut_ptr());
+                        this.assume_init()
+                    }
                 }
             }
         })
@@ -387,20 +1100,108 @@ fn emit_impl_display(
     }
 }
 
+// NOTE: C++ streams formatted output back through the `write` method of this writer, which the bridge declares as an
+// opaque Rust type so that the `Formatter` is only ever borrowed by C++, never reconstructed from an address
 #[cfg(feature = "alloc")]
-fn emit_impl_moveit_copy_new(
+fn emit_struct_cxx_fmt_writer(info: &CxxAutoArtifactInfo) -> Option<syn::ItemStruct> {
+    if !info.is_rust_debug && !info.is_rust_display {
+        return None;
+    }
+    Some(syn::parse_quote! {
+        struct CxxFmtWriter<'a, 'f>(&'a mut ::core::fmt::Formatter<'f>);
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_cxx_fmt_writer(info: &CxxAutoArtifactInfo) -> Option<syn::ItemImpl> {
+    if !info.is_rust_debug && !info.is_rust_display {
+        return None;
+    }
+    Some(syn::parse_quote! {
+        impl CxxFmtWriter<'_, '_> {
+            fn write(&mut self, bytes: &[u8]) -> bool {
+                ::cxx_auto::fmt::write_utf8_lossy(self.0, bytes).is_ok()
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_display(
     info: &CxxAutoArtifactInfo,
     ident: &syn::Ident,
     generics_binder: &syn::Generics,
     generics: &syn::Generics,
 ) -> Option<syn::ItemImpl> {
-    if info.is_rust_copy_new {
+    if info.is_rust_display {
+        let display = emit_fmt_call(display_fmt(info), true);
         Some(syn::parse_quote! {
-            impl #generics_binder ::moveref::CopyNew for #ident #generics {
-                #[inline]
-                unsafe fn copy_new(that: &Self, this: ::core::pin::Pin<&mut ::core::mem::MaybeUninit<Self>>) {
-                    let this = this.get_unchecked_mut().as_mut_ptr();
-                    self::ffi::cxx_copy_new(this, that);
+            impl #generics_binder ::core::fmt::Display for #ident #generics {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #display
+                }
+            }
+        })
+    } else {
+        None
+    }
+}
+
+// NOTE: `Clone` is derived along with `Copy`, otherwise it copy-constructs by value, which requires `Unpin`
+#[cfg(feature = "alloc")]
+fn has_clone(info: &CxxAutoArtifactInfo) -> bool {
+    info.is_rust_copy_new && info.is_rust_unpin && !info.is_rust_copy
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_clone(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !has_clone(info) {
+        return None;
+    }
+    let clone_from: Option<syn::ImplItemFn> = if info.cxx_is_copy_assignable {
+        Some(syn::parse_quote! {
+            #[inline]
+            fn clone_from(&mut self, source: &Self) {
+                self::ffi::cxx_copy_assign(::core::pin::Pin::new(self), source);
+            }
+        })
+    } else {
+        None
+    };
+    Some(syn::parse_quote! {
+        impl #generics_binder ::core::clone::Clone for #ident #generics {
+            #[inline]
+            fn clone(&self) -> Self {
+                let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                unsafe {
+                    self::ffi::cxx_copy_new(this.as_mut_ptr(), self);
+                    this.assume_init()
+                }
+            }
+            #clone_from
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_moveit_copy_new(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.is_rust_copy_new {
+        Some(syn::parse_quote! {
+            impl #generics_binder ::moveref::CopyNew for #ident #generics {
+                #[inline]
+                unsafe fn copy_new(that: &Self, this: ::core::pin::Pin<&mut ::core::mem::MaybeUninit<Self>>) {
+                    let this = this.get_unchecked_mut().as_mut_ptr();
+                    self::ffi::cxx_copy_new(this, that);
                 }
             }
         })
@@ -409,6 +1210,220 @@ fn emit_impl_moveit_copy_new(
     }
 }
 
+#[cfg(feature = "alloc")]
+fn emit_impl_box_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !info.is_rust_default && !info.is_rust_copy_new && !info.is_rust_move_new {
+        return None;
+    }
+    let default_box: Option<syn::ImplItemFn> = if info.is_rust_default {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn default_box() -> ::core::pin::Pin<::alloc::boxed::Box<Self>> {
+                let this = ::alloc::boxed::Box::into_raw(::alloc::boxed::Box::new(::core::mem::MaybeUninit::<Self>::uninit())).cast::<Self>();
+                unsafe {
+                    self::ffi::cxx_default_new(this);
+                    ::alloc::boxed::Box::into_pin(::alloc::boxed::Box::from_raw(this))
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let copy_box: Option<syn::ImplItemFn> = if info.is_rust_copy_new {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn copy_box(&self) -> ::core::pin::Pin<::alloc::boxed::Box<Self>> {
+                let this = ::alloc::boxed::Box::into_raw(::alloc::boxed::Box::new(::core::mem::MaybeUninit::<Self>::uninit())).cast::<Self>();
+                unsafe {
+                    self::ffi::cxx_copy_new(this, self);
+                    ::alloc::boxed::Box::into_pin(::alloc::boxed::Box::from_raw(this))
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let move_box: Option<syn::ImplItemFn> = if info.is_rust_move_new {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn move_box(that: ::core::pin::Pin<::moveref::MoveRef<'_, Self>>) -> ::core::pin::Pin<::alloc::boxed::Box<Self>> {
+                let this = ::alloc::boxed::Box::into_raw(::alloc::boxed::Box::new(::core::mem::MaybeUninit::<Self>::uninit())).cast::<Self>();
+                unsafe {
+                    let mut that = ::core::pin::Pin::into_inner_unchecked(that);
+                    self::ffi::cxx_move_new(this, &mut *that);
+                    ::alloc::boxed::Box::into_pin(::alloc::boxed::Box::from_raw(this))
+                }
+            }
+        })
+    } else {
+        None
+    };
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #default_box
+            #copy_box
+            #move_box
+        }
+    })
+}
+
+// NOTE: `UniquePtr` and `SharedPtr` are destroyed on the C++ side, so they are available even when Rust does not
+// implement `Drop`; they are only bound for entries which opt in with `smart_pointers`, since each one instantiates
+// the `cxx` smart pointer glue for the type
+#[cfg(feature = "alloc")]
+fn has_smart_ptrs(info: &CxxAutoArtifactInfo) -> bool {
+    info.smart_pointers && info.cxx_is_destructible
+}
+
+#[cfg(feature = "alloc")]
+fn has_cxx_vector(info: &CxxAutoArtifactInfo) -> bool {
+    info.cxx_is_destructible && info.cxx_is_move_constructible
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_unique_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let ptr = syn::parse_quote!(::cxx::UniquePtr);
+    emit_impl_smart_ptr_constructors(info, ident, generics_binder, generics, "unique", &ptr)
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_shared_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let ptr = syn::parse_quote!(::cxx::SharedPtr);
+    emit_impl_smart_ptr_constructors(info, ident, generics_binder, generics, "shared", &ptr)
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_smart_ptr_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+    name: &str,
+    ptr: &syn::Path,
+) -> Option<syn::ItemImpl> {
+    if !has_smart_ptrs(info) {
+        return None;
+    }
+    if !info.is_rust_default && !info.is_rust_copy_new && !info.is_rust_move_new {
+        return None;
+    }
+    let default_ident = syn::Ident::new(&::alloc::format!("default_{name}"), Span::call_site());
+    let copy_ident = syn::Ident::new(&::alloc::format!("copy_{name}"), Span::call_site());
+    let move_ident = syn::Ident::new(&::alloc::format!("move_{name}"), Span::call_site());
+    let cxx_default = syn::Ident::new(&::alloc::format!("cxx_default_{name}"), Span::call_site());
+    let cxx_copy = syn::Ident::new(&::alloc::format!("cxx_copy_{name}"), Span::call_site());
+    let cxx_move = syn::Ident::new(&::alloc::format!("cxx_move_{name}"), Span::call_site());
+    let default_ptr: Option<syn::ImplItemFn> = if info.is_rust_default {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn #default_ident() -> #ptr<Self> {
+                self::ffi::#cxx_default()
+            }
+        })
+    } else {
+        None
+    };
+    let copy_ptr: Option<syn::ImplItemFn> = if info.is_rust_copy_new {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn #copy_ident(&self) -> #ptr<Self> {
+                self::ffi::#cxx_copy(self)
+            }
+        })
+    } else {
+        None
+    };
+    let move_ptr: Option<syn::ImplItemFn> = if info.is_rust_move_new {
+        Some(syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn #move_ident(that: ::core::pin::Pin<::moveref::MoveRef<'_, Self>>) -> #ptr<Self> {
+                unsafe {
+                    let mut that = ::core::pin::Pin::into_inner_unchecked(that);
+                    self::ffi::#cxx_move(&mut *that)
+                }
+            }
+        })
+    } else {
+        None
+    };
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #default_ptr
+            #copy_ptr
+            #move_ptr
+        }
+    })
+}
+
+// NOTE: assignment overwrites the existing C++ object in place, so the receiver is only pinned when the type is not
+// `Unpin`
+#[cfg(feature = "alloc")]
+fn emit_impl_assignments(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !info.cxx_is_copy_assignable && !info.cxx_is_move_assignable {
+        return None;
+    }
+    let (receiver, this): (syn::FnArg, syn::Expr) = if info.is_rust_unpin {
+        (syn::parse_quote!(&mut self), syn::parse_quote!(::core::pin::Pin::new(self)))
+    } else {
+        (syn::parse_quote!(self: ::core::pin::Pin<&mut Self>), syn::parse_quote!(self))
+    };
+    let assign_from: Option<syn::ImplItemFn> = if info.cxx_is_copy_assignable {
+        Some(syn::parse_quote! {
+            #[inline]
+            pub fn assign_from(#receiver, that: &Self) {
+                self::ffi::cxx_copy_assign(#this, that);
+            }
+        })
+    } else {
+        None
+    };
+    let move_assign_from: Option<syn::ImplItemFn> = if info.cxx_is_move_assignable {
+        Some(syn::parse_quote! {
+            #[inline]
+            pub fn move_assign_from(#receiver, that: ::core::pin::Pin<::moveref::MoveRef<'_, Self>>) {
+                unsafe {
+                    let mut that = ::core::pin::Pin::into_inner_unchecked(that);
+                    self::ffi::cxx_move_assign(#this, &mut *that);
+                }
+            }
+        })
+    } else {
+        None
+    };
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #assign_from
+            #move_assign_from
+        }
+    })
+}
+
 #[cfg(feature = "alloc")]
 fn emit_impl_moveit_move_new(
     info: &CxxAutoArtifactInfo,
@@ -543,17 +1558,7 @@ fn emit_impl_partial_ord(
             syn::parse_quote! {
                 #[inline]
                 fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
-                    let res = self::ffi::cxx_operator_three_way_comparison(self, other);
-                    if res == -1 {
-                        Some(::core::cmp::Ordering::Less)
-                    } else if res == 1 {
-                        Some(::core::cmp::Ordering::Greater)
-                    } else if res == 0 {
-                        Some(::core::cmp::Ordering::Equal)
-                    } else {
-                        ::core::assert_eq!(res, ::core::primitive::i8::MAX);
-                        None
-                    }
+                    self::ffi::cxx_operator_three_way_comparison(self, other).to_partial_ordering()
                 }
             }
         };
@@ -572,52 +1577,988 @@ fn emit_impl_partial_ord(
 }
 
 #[cfg(feature = "alloc")]
-fn emit_impl_ord(
+fn emit_impl_ord(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.is_rust_ord {
+        Some(syn::parse_quote! {
+            impl #generics_binder ::core::cmp::Ord for #ident #generics {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    self::ffi::cxx_operator_three_way_comparison(self, other).to_ordering()
+                }
+            }
+        })
+    } else {
+        None
+    }
+}
+
+// NOTE: the right-hand side is only ever compared from the left, so no mirrored impls are emitted for it
+#[cfg(feature = "alloc")]
+fn emit_impls_comparisons(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> ::alloc::vec::Vec<syn::ItemImpl> {
+    let mut items = ::alloc::vec::Vec::new();
+    for (index, (comparison, _, cxx_is_partially_ordered)) in info.comparisons.iter().enumerate() {
+        let cxx_operator_equal_with =
+            syn::Ident::new(&::alloc::format!("cxx_operator_equal_with_{index}"), Span::call_site());
+        let rhs = comparison.emit_path();
+        let other: syn::Expr = if comparison.is_primitive() {
+            syn::parse_quote!(*other)
+        } else {
+            syn::parse_quote!(other)
+        };
+        items.push(syn::parse_quote! {
+            impl #generics_binder ::core::cmp::PartialEq<#rhs> for #ident #generics {
+                #[inline]
+                fn eq(&self, other: &#rhs) -> bool {
+                    self::ffi::#cxx_operator_equal_with(self, #other)
+                }
+            }
+        });
+        if *cxx_is_partially_ordered {
+            let cxx_operator_three_way_comparison_with = syn::Ident::new(
+                &::alloc::format!("cxx_operator_three_way_comparison_with_{index}"),
+                Span::call_site(),
+            );
+            items.push(syn::parse_quote! {
+                impl #generics_binder ::core::cmp::PartialOrd<#rhs> for #ident #generics {
+                    #[inline]
+                    fn partial_cmp(&self, other: &#rhs) -> Option<::core::cmp::Ordering> {
+                        self::ffi::#cxx_operator_three_way_comparison_with(self, #other).to_partial_ordering()
+                    }
+                }
+            });
+        }
+    }
+    items
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_hash(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.is_rust_hash {
+        Some(syn::parse_quote! {
+            impl #generics_binder ::core::hash::Hash for #ident #generics {
+                #[inline]
+                fn hash<H>(&self, state: &mut H)
+                where
+                    H: ::core::hash::Hasher,
+                {
+                    let hash = self::ffi::cxx_hash(self);
+                    state.write_usize(hash);
+                }
+            }
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.constructors.is_empty() {
+        return None;
+    }
+    let span = Span::call_site();
+    let constructors = info.constructors.iter().map(|constructor| -> syn::ImplItemFn {
+        let rust_name = syn::Ident::new(constructor.rust_name, span);
+        let cxx_placement_new =
+            syn::Ident::new(&::alloc::format!("cxx_placement_new_{}", constructor.rust_name), span);
+        let arg_idents = constructor.args.iter().map(crate::CxxAutoArg::emit_ident);
+        let (new_binder, new_bound, new_where): (
+            Option<syn::Generics>,
+            Option<syn::TypeParamBound>,
+            Option<syn::WhereClause>,
+        ) = if constructor.has_reference_args() {
+            let outlives = generics.lifetimes().map(|param| &param.lifetime).collect::<::alloc::vec::Vec<_>>();
+            let new_where = if outlives.is_empty() {
+                None
+            } else {
+                Some(syn::parse_quote!(where #(#outlives: 'new),*))
+            };
+            (Some(syn::parse_quote!(<'new>)), Some(syn::parse_quote!('new)), new_where)
+        } else {
+            (None, None, None)
+        };
+        let args = constructor.args.iter().map(|arg| {
+            let mut fn_arg = arg.emit_fn_arg();
+            if let syn::FnArg::Typed(syn::PatType { ty, .. }) = &mut fn_arg {
+                if let syn::Type::Reference(reference) = &mut **ty {
+                    reference.lifetime.get_or_insert_with(|| syn::parse_quote!('new));
+                }
+            }
+            fn_arg
+        });
+        let new_bound = new_bound.into_iter();
+        syn::parse_quote! {
+            #[inline]
+            pub fn #rust_name #new_binder (#(#args),*) -> impl ::moveref::New<Output = #ident #generics> #(+ #new_bound)*
+            #new_where
+            {
+                unsafe {
+                    ::moveref::new::by_raw(move |this| {
+                        let this = this.get_unchecked_mut().as_mut_ptr();
+                        self::ffi::#cxx_placement_new(this, #(#arg_idents),*);
+                    })
+                }
+            }
+        }
+    });
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #(#constructors)*
+        }
+    })
+}
+
+// NOTE: potentially throwing conversions panic here, since a `New` cannot report failure, which is documented on the
+// generated constructor
+#[cfg(feature = "alloc")]
+fn emit_impl_conversion_constructors(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.conversions.is_empty() {
+        return None;
+    }
+    let panics = if info.is_rust_unpin {
+        " Panics when the `New` is run if the C++ conversion throws; `TryFrom` reports the exception as a \
+         `ConvertError` instead."
+    } else {
+        " Panics when the `New` is run if the C++ conversion throws."
+    };
+    let constructors = info
+        .conversions
+        .iter()
+        .enumerate()
+        .map(|(index, (conversion, _, is_nothrow))| -> syn::ImplItemFn {
+            let rust_name = conversion.emit_ident();
+            let cxx_convert_new = syn::Ident::new(&::alloc::format!("cxx_convert_new_{index}"), Span::call_site());
+            let from = conversion.from.emit_path();
+            let (new_binder, new_bound, new_where, that): (
+                Option<syn::Generics>,
+                Option<syn::TypeParamBound>,
+                Option<syn::WhereClause>,
+                syn::Type,
+            ) = if conversion.from.is_primitive() {
+                (None, None, None, syn::parse_quote!(#from))
+            } else {
+                let outlives = generics.lifetimes().map(|param| &param.lifetime).collect::<::alloc::vec::Vec<_>>();
+                let new_where = if outlives.is_empty() {
+                    None
+                } else {
+                    Some(syn::parse_quote!(where #(#outlives: 'new),*))
+                };
+                (
+                    Some(syn::parse_quote!(<'new>)),
+                    Some(syn::parse_quote!('new)),
+                    new_where,
+                    syn::parse_quote!(&'new #from),
+                )
+            };
+            let new_bound = new_bound.into_iter();
+            let convert: syn::Stmt = if *is_nothrow {
+                syn::parse_quote! {
+                    self::ffi::#cxx_convert_new(this, that);
+                }
+            } else {
+                syn::parse_quote! {
+                    if let Err(err) =
+                        ::cxx_auto::convert::ConvertError::check(self::ffi::#cxx_convert_new(this, that))
+                    {
+                        panic!("cxx-auto: {err}");
+                    }
+                }
+            };
+            let panics = (!is_nothrow).then_some(panics).into_iter();
+            syn::parse_quote! {
+                #(
+                    #[doc = " # Panics"]
+                    #[doc = ""]
+                    #[doc = #panics]
+                )*
+                #[inline]
+                pub fn #rust_name #new_binder (that: #that)
+                    -> impl ::moveref::New<Output = #ident #generics> #(+ #new_bound)*
+                #new_where
+                {
+                    unsafe {
+                        ::moveref::new::by_raw(move |this| {
+                            let this = this.get_unchecked_mut().as_mut_ptr();
+                            #convert
+                        })
+                    }
+                }
+            }
+        });
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #(#constructors)*
+        }
+    })
+}
+
+// NOTE: conversions that may throw are exposed through `TryFrom`, where the exception becomes a `ConvertError`
+#[cfg(feature = "alloc")]
+fn emit_impls_conversions(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> ::alloc::vec::Vec<syn::ItemImpl> {
+    if !info.is_rust_unpin {
+        return ::alloc::vec![];
+    }
+    info.conversions
+        .iter()
+        .enumerate()
+        .map(|(index, (conversion, _, is_nothrow))| -> syn::ItemImpl {
+            let cxx_convert_new = syn::Ident::new(&::alloc::format!("cxx_convert_new_{index}"), Span::call_site());
+            let from = conversion.from.emit_path();
+            let that: syn::Type = if conversion.from.is_primitive() {
+                syn::parse_quote!(#from)
+            } else {
+                syn::parse_quote!(&#from)
+            };
+            if *is_nothrow {
+                syn::parse_quote! {
+                    impl #generics_binder ::core::convert::From<#that> for #ident #generics {
+                        #[inline]
+                        fn from(that: #that) -> Self {
+                            let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                            unsafe {
+                                self::ffi::#cxx_convert_new(this.as_mut_ptr(), that);
+                                this.assume_init()
+                            }
+                        }
+                    }
+                }
+            } else {
+                syn::parse_quote! {
+                    impl #generics_binder ::core::convert::TryFrom<#that> for #ident #generics {
+                        type Error = ::cxx_auto::convert::ConvertError;
+
+                        #[inline]
+                        fn try_from(that: #that) -> Result<Self, Self::Error> {
+                            let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                            let converted = unsafe { self::ffi::#cxx_convert_new(this.as_mut_ptr(), that) };
+                            ::cxx_auto::convert::ConvertError::check(converted)?;
+                            Ok(unsafe { this.assume_init() })
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_fields(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if info.fields.is_empty() || has_typed_fields(info) {
+        return None;
+    }
+    let span = Span::call_site();
+    let accessors = info.fields.iter().flat_map(|(field, offset, _)| -> [syn::ImplItemFn; 2] {
+        let rust_name = field.emit_ident();
+        let rust_name_mut = syn::Ident::new(&::alloc::format!("{}_mut", field.rust_name()), span);
+        let ty = field.emit_type();
+        let offset = proc_macro2::Literal::usize_unsuffixed(*offset);
+        let accessor = syn::parse_quote! {
+            #[inline]
+            #[must_use]
+            pub fn #rust_name(&self) -> &#ty {
+                unsafe { &*::core::ptr::addr_of!(*self).cast::<u8>().add(#offset).cast::<#ty>() }
+            }
+        };
+        let accessor_mut = if info.is_rust_unpin {
+            syn::parse_quote! {
+                #[inline]
+                pub fn #rust_name_mut(&mut self) -> &mut #ty {
+                    unsafe { &mut *::core::ptr::addr_of_mut!(*self).cast::<u8>().add(#offset).cast::<#ty>() }
+                }
+            }
+        } else {
+            syn::parse_quote! {
+                #[inline]
+                pub fn #rust_name_mut(self: ::core::pin::Pin<&mut Self>) -> ::core::pin::Pin<&mut #ty> {
+                    unsafe {
+                        self.map_unchecked_mut(|this| {
+                            &mut *::core::ptr::addr_of_mut!(*this).cast::<u8>().add(#offset).cast::<#ty>()
+                        })
+                    }
+                }
+            }
+        };
+        [accessor, accessor_mut]
+    });
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #(#accessors)*
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_range_iter_ident(ident: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(&::alloc::format!("{ident}Iter"), Span::call_site())
+}
+
+#[cfg(feature = "alloc")]
+fn emit_generics_with_lifetime(
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+    name: &str,
+) -> (syn::Generics, syn::Generics) {
+    let lifetime = syn::Lifetime::new(&::alloc::format!("'{name}"), Span::call_site());
+    let lifetime = syn::GenericParam::from(syn::LifetimeParam::new(lifetime));
+    let mut generics_binder = generics_binder.clone();
+    generics_binder.params.insert(0, lifetime.clone());
+    let mut generics = generics.clone();
+    generics.params.insert(0, lifetime);
+    (generics_binder, generics)
+}
+
+#[cfg(feature = "alloc")]
+fn emit_struct_range_iter(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemStruct> {
+    info.range_item.as_ref()?;
+    let iter_ident = emit_range_iter_ident(ident);
+    let (iter_generics_binder, _) = emit_generics_with_lifetime(generics_binder, generics, "iter");
+    Some(syn::parse_quote! {
+        pub struct #iter_ident #iter_generics_binder {
+            cursor: ::cxx::UniquePtr<self::ffi::CxxRangeCursor>,
+            _marker: ::core::marker::PhantomData<&'iter #ident #generics>,
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_range_iter(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    info.range_item.as_ref()?;
+    let iter_ident = emit_range_iter_ident(ident);
+    let (_, iter_generics) = emit_generics_with_lifetime(generics_binder, generics, "iter");
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #[inline]
+            #[must_use]
+            pub fn iter<'iter>(&'iter self) -> #iter_ident #iter_generics {
+                #iter_ident {
+                    cursor: self::ffi::cxx_range_cursor_new(self),
+                    _marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_range_iter_iterator(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let item = info.range_item.as_ref()?.emit_path();
+    let iter_ident = emit_range_iter_ident(ident);
+    let (iter_generics_binder, iter_generics) = emit_generics_with_lifetime(generics_binder, generics, "iter");
+    Some(syn::parse_quote! {
+        impl #iter_generics_binder ::core::iter::Iterator for #iter_ident #iter_generics {
+            type Item = &'iter #item;
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let item = self::ffi::cxx_range_cursor_next(self.cursor.pin_mut());
+                unsafe { item.as_ref() }
+            }
+        }
+    })
+}
+
+// NOTE: the C++ cursor keeps returning `nullptr` once it has reached the end of the range
+#[cfg(feature = "alloc")]
+fn emit_impl_range_iter_fused_iterator(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    info.range_item.as_ref()?;
+    let iter_ident = emit_range_iter_ident(ident);
+    let (iter_generics_binder, iter_generics) = emit_generics_with_lifetime(generics_binder, generics, "iter");
+    Some(syn::parse_quote! {
+        impl #iter_generics_binder ::core::iter::FusedIterator for #iter_ident #iter_generics {}
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_into_iterator(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let item = info.range_item.as_ref()?.emit_path();
+    let iter_ident = emit_range_iter_ident(ident);
+    let (iter_generics_binder, iter_generics) = emit_generics_with_lifetime(generics_binder, generics, "iter");
+    Some(syn::parse_quote! {
+        impl #iter_generics_binder ::core::iter::IntoIterator for &'iter #ident #generics {
+            type Item = &'iter #item;
+            type IntoIter = #iter_ident #iter_generics;
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    })
+}
+
+// NOTE: `IndexMut` hands out `&mut` to the element, which is only sound for builtin elements of an `Unpin` type;
+// otherwise mutable indexing goes through `index_pin_mut`
+#[cfg(feature = "alloc")]
+fn has_index_mut(info: &CxxAutoArtifactInfo) -> bool {
+    match &info.index {
+        Some(index) => info.cxx_has_operator_subscript && info.is_rust_unpin && index.output.cxx_name.is_none(),
+        None => false,
+    }
+}
+
+// NOTE: `check_trait_decisions` requires `size()` whenever `index` is set, so every safe indexing path is bounds
+// checked before it reaches the C++ `operator[]`
+#[cfg(feature = "alloc")]
+fn emit_index_bounds_check(this: &syn::Expr) -> syn::Stmt {
+    syn::parse_quote! {
+        {
+            let len = self::ffi::cxx_size(#this);
+            ::core::assert!(
+                usize::try_from(index).is_ok_and(|index| index < len),
+                "index out of bounds: the len is {len} but the index is {index}",
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_index(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let index = info.index.as_ref()?;
+    let ty = index.emit_type();
+    let output = index.output.emit_path();
+    let bounds_check = emit_index_bounds_check(&syn::parse_quote!(self));
+    Some(syn::parse_quote! {
+        impl #generics_binder ::core::ops::Index<#ty> for #ident #generics {
+            type Output = #output;
+            #[inline]
+            fn index(&self, index: #ty) -> &Self::Output {
+                #bounds_check
+                self::ffi::cxx_operator_subscript_const(self, index)
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_index_mut(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !has_index_mut(info) {
+        return None;
+    }
+    let ty = info.index.as_ref()?.emit_type();
+    let bounds_check = emit_index_bounds_check(&syn::parse_quote!(self));
+    Some(syn::parse_quote! {
+        impl #generics_binder ::core::ops::IndexMut<#ty> for #ident #generics {
+            #[inline]
+            fn index_mut(&mut self, index: #ty) -> &mut Self::Output {
+                #bounds_check
+                self::ffi::cxx_operator_subscript(::core::pin::Pin::new(self), index)
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_index_pin_mut(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    let index = info.index.as_ref()?;
+    if !info.cxx_has_operator_subscript || has_index_mut(info) {
+        return None;
+    }
+    let ty = index.emit_type();
+    let output = index.output.emit_path();
+    let returns: syn::Type = if index.output.cxx_name.is_some() {
+        syn::parse_quote!(::core::pin::Pin<&mut #output>)
+    } else {
+        syn::parse_quote!(&mut #output)
+    };
+    let bounds_check = emit_index_bounds_check(&syn::parse_quote!(&self));
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #[inline]
+            pub fn index_pin_mut(self: ::core::pin::Pin<&mut Self>, index: #ty) -> #returns {
+                #bounds_check
+                self::ffi::cxx_operator_subscript(self, index)
+            }
+        }
+    })
+}
+
+// NOTE: `FromIterator` is detected for any container-like type, but can only be generated once the element type is
+// known from `range_item`
+#[cfg(feature = "alloc")]
+fn has_from_iterator(info: &CxxAutoArtifactInfo) -> bool {
+    info.is_rust_from_iterator && info.range_item.is_some()
+}
+
+// NOTE: the C++ constructor takes a `[first, last)` pointer pair, so the items are collected into a `Vec` first, which
+// costs one extra allocation; the C++ container then copy-constructs every item from the `Vec`, which drops the
+// originals afterwards, so each item is effectively cloned through its C++ copy constructor; the same applies to
+// `FromIterator`
+#[cfg(feature = "alloc")]
+fn emit_impl_from_iter_new(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !has_from_iterator(info) {
+        return None;
+    }
+    let item = info.range_item.as_ref()?.emit_path();
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            /// Constructs the C++ container from the items of `iter`.
+            ///
+            /// The items are first collected into a `Vec`, since the C++ constructor takes a contiguous range of
+            /// elements, which costs one extra allocation. The container then copy-constructs its elements from the
+            /// collected items, which are dropped afterwards, so every item is cloned once through the C++ copy
+            /// constructor of the item type, as if it were `Clone`.
+            #[inline]
+            pub fn from_iter_new<I>(iter: I) -> impl ::moveref::New<Output = #ident #generics>
+            where
+                I: ::core::iter::IntoIterator<Item = #item>,
+            {
+                let items = iter.into_iter().collect::<::alloc::vec::Vec<_>>();
+                unsafe {
+                    ::moveref::new::by_raw(move |this| {
+                        let this = this.get_unchecked_mut().as_mut_ptr();
+                        let first = items.as_ptr();
+                        let last = first.add(items.len());
+                        self::ffi::cxx_from_iter_new(this, first, last);
+                    })
+                }
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_from_iterator(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !has_from_iterator(info) || !info.is_rust_unpin {
+        return None;
+    }
+    let item = info.range_item.as_ref()?.emit_path();
+    Some(syn::parse_quote! {
+        impl #generics_binder ::core::iter::FromIterator<#item> for #ident #generics {
+            #[inline]
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::core::iter::IntoIterator<Item = #item>,
+            {
+                let items = iter.into_iter().collect::<::alloc::vec::Vec<_>>();
+                let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                unsafe {
+                    let first = items.as_ptr();
+                    let last = first.add(items.len());
+                    self::ffi::cxx_from_iter_new(this.as_mut_ptr(), first, last);
+                    this.assume_init()
+                }
+            }
+        }
+    })
+}
+
+// NOTE: the input is extracted once into a boxed temporary so that the error is reported eagerly, and the returned
+// `New` move-constructs from it; the moved-from temporary is then destroyed along with the box
+#[cfg(feature = "alloc")]
+fn emit_impl_parse_new(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !info.is_rust_from_str || !info.is_rust_move_new {
+        return None;
+    }
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #[inline]
+            pub fn parse_new(
+                text: &str,
+            ) -> Result<impl ::moveref::New<Output = #ident #generics>, ::cxx_auto::parse::ParseError> {
+                let that = ::alloc::boxed::Box::into_raw(
+                    ::alloc::boxed::Box::new(::core::mem::MaybeUninit::<Self>::uninit()),
+                );
+                let status = unsafe { self::ffi::cxx_parse_new(that.cast::<Self>(), text) };
+                if let Err(err) = ::cxx_auto::parse::ParseError::check(status) {
+                    drop(unsafe { ::alloc::boxed::Box::from_raw(that) });
+                    return Err(err);
+                }
+                let mut that = unsafe { ::alloc::boxed::Box::into_pin(::alloc::boxed::Box::from_raw(that.cast::<Self>())) };
+                Ok(unsafe {
+                    ::moveref::new::by_raw(move |this| {
+                        let this = this.get_unchecked_mut().as_mut_ptr();
+                        self::ffi::cxx_move_new(this, that.as_mut().get_unchecked_mut());
+                    })
+                })
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn emit_impl_from_str(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> Option<syn::ItemImpl> {
+    if !info.is_rust_from_str || !info.is_rust_unpin {
+        return None;
+    }
+    Some(syn::parse_quote! {
+        impl #generics_binder ::core::str::FromStr for #ident #generics {
+            type Err = ::cxx_auto::parse::ParseError;
+
+            #[inline]
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                let status = unsafe { self::ffi::cxx_parse_new(this.as_mut_ptr(), text) };
+                ::cxx_auto::parse::ParseError::check(status)?;
+                Ok(unsafe { this.assume_init() })
+            }
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum OperatorKind {
+    Binary,
+    Unary,
+    CompoundAssignment,
+}
+
+// NOTE: each entry is (C++ operator name, Rust trait, Rust method), mirroring `CXX_AUTO_OPERATORS` in the header
+#[cfg(feature = "alloc")]
+const BINARY_OPERATORS: [(&str, &str, &str); 8] = [
+    ("plus", "Add", "add"),
+    ("minus", "Sub", "sub"),
+    ("multiplies", "Mul", "mul"),
+    ("divides", "Div", "div"),
+    ("modulus", "Rem", "rem"),
+    ("bit_and", "BitAnd", "bitand"),
+    ("bit_or", "BitOr", "bitor"),
+    ("bit_xor", "BitXor", "bitxor"),
+];
+
+#[cfg(feature = "alloc")]
+const UNARY_OPERATORS: [(&str, &str, &str); 2] = [("negate", "Neg", "neg"), ("bit_not", "Not", "not")];
+
+#[cfg(feature = "alloc")]
+const COMPOUND_ASSIGNMENT_OPERATORS: [(&str, &str, &str); 8] = [
+    ("plus_assign", "AddAssign", "add_assign"),
+    ("minus_assign", "SubAssign", "sub_assign"),
+    ("multiplies_assign", "MulAssign", "mul_assign"),
+    ("divides_assign", "DivAssign", "div_assign"),
+    ("modulus_assign", "RemAssign", "rem_assign"),
+    ("bit_and_assign", "BitAndAssign", "bitand_assign"),
+    ("bit_or_assign", "BitOrAssign", "bitor_assign"),
+    ("bit_xor_assign", "BitXorAssign", "bitxor_assign"),
+];
+
+#[cfg(feature = "alloc")]
+pub(crate) fn operators() -> impl Iterator<Item = (OperatorKind, &'static str, &'static str, &'static str)> {
+    let with_kind = |kind| move |(cxx_operator, rust_trait, rust_method)| (kind, cxx_operator, rust_trait, rust_method);
+    BINARY_OPERATORS
+        .into_iter()
+        .map(with_kind(OperatorKind::Binary))
+        .chain(UNARY_OPERATORS.into_iter().map(with_kind(OperatorKind::Unary)))
+        .chain(
+            COMPOUND_ASSIGNMENT_OPERATORS
+                .into_iter()
+                .map(with_kind(OperatorKind::CompoundAssignment)),
+        )
+}
+
+#[cfg(feature = "alloc")]
+fn detected_operators(
+    info: &CxxAutoArtifactInfo,
+) -> impl Iterator<Item = (OperatorKind, &'static str, &'static str, &'static str)> + '_ {
+    operators().filter(|&(_, cxx_operator, ..)| info.cxx_operators.contains(&cxx_operator))
+}
+
+#[cfg(feature = "alloc")]
+fn emit_cxx_operator_ident(cxx_operator: &str) -> syn::Ident {
+    syn::Ident::new(&::alloc::format!("cxx_operator_{cxx_operator}"), Span::call_site())
+}
+
+// NOTE: operators on types which Rust cannot move return a named `moveref::New` implementor, since associated types
+// cannot be `impl Trait`
+#[cfg(feature = "alloc")]
+fn emit_items_operation_new(
+    info: &CxxAutoArtifactInfo,
+    ident: &syn::Ident,
+    generics_binder: &syn::Generics,
+    generics: &syn::Generics,
+) -> ::alloc::vec::Vec<syn::Item> {
+    let mut items = ::alloc::vec::Vec::new();
+    if info.is_rust_unpin {
+        return items;
+    }
+    let (operation_generics_binder, operation_generics) = emit_generics_with_lifetime(generics_binder, generics, "op");
+    for kind in [OperatorKind::Binary, OperatorKind::Unary] {
+        if !detected_operators(info).any(|(operator_kind, ..)| operator_kind == kind) {
+            continue;
+        }
+        let operation = emit_operation_ident(ident, kind);
+        let (fields, operands): (syn::FieldsNamed, syn::Expr) = if kind == OperatorKind::Binary {
+            (
+                syn::parse_quote!({
+                    operator: unsafe fn(*mut #ident #generics, &#ident #generics, &#ident #generics),
+                    lhs: &'op #ident #generics,
+                    rhs: &'op #ident #generics,
+                }),
+                syn::parse_quote!((self.operator)(this, self.lhs, self.rhs)),
+            )
+        } else {
+            (
+                syn::parse_quote!({
+                    operator: unsafe fn(*mut #ident #generics, &#ident #generics),
+                    arg: &'op #ident #generics,
+                }),
+                syn::parse_quote!((self.operator)(this, self.arg)),
+            )
+        };
+        items.push(syn::parse_quote! {
+            pub struct #operation #operation_generics_binder #fields
+        });
+        items.push(syn::parse_quote! {
+            unsafe impl #operation_generics_binder ::moveref::New for #operation #operation_generics {
+                type Output = #ident #generics;
+                #[inline]
+                unsafe fn new(self, this: ::core::pin::Pin<&mut ::core::mem::MaybeUninit<Self::Output>>) {
+                    let this = this.get_unchecked_mut().as_mut_ptr();
+                    #operands;
+                }
+            }
+        });
+    }
+    items
+}
+
+#[cfg(feature = "alloc")]
+fn emit_operation_ident(ident: &syn::Ident, kind: OperatorKind) -> syn::Ident {
+    let arity = match kind {
+        OperatorKind::Binary => "Binary",
+        OperatorKind::Unary | OperatorKind::CompoundAssignment => "Unary",
+    };
+    syn::Ident::new(&::alloc::format!("{ident}{arity}Operation"), Span::call_site())
+}
+
+// NOTE: the C++ helpers are `noexcept`, so an exception escaping from a C++ operator calls `std::terminate`
+#[cfg(feature = "alloc")]
+fn emit_impls_operators(
     info: &CxxAutoArtifactInfo,
     ident: &syn::Ident,
     generics_binder: &syn::Generics,
     generics: &syn::Generics,
-) -> Option<syn::ItemImpl> {
-    if info.is_rust_ord {
-        Some(syn::parse_quote! {
-            impl #generics_binder ::core::cmp::Ord for #ident #generics {
-                #[inline]
-                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
-                    let res = self::ffi::cxx_operator_three_way_comparison(self, other);
-                    res.cmp(&0)
-                }
+) -> ::alloc::vec::Vec<syn::ItemImpl> {
+    let span = Span::call_site();
+    let doc = " An exception thrown by the C++ operator calls `std::terminate`, since it cannot unwind into Rust.";
+    let (operation_generics_binder, operation_generics) = emit_generics_with_lifetime(generics_binder, generics, "op");
+    detected_operators(info)
+        .map(|(kind, cxx_operator, rust_trait, rust_method)| -> syn::ItemImpl {
+            let operation = emit_operation_ident(ident, kind);
+            let cxx_operator = emit_cxx_operator_ident(cxx_operator);
+            let rust_trait = syn::Ident::new(rust_trait, span);
+            let rust_method = syn::Ident::new(rust_method, span);
+            match (kind, info.is_rust_unpin) {
+                (OperatorKind::Binary, true) => syn::parse_quote! {
+                    impl #generics_binder ::core::ops::#rust_trait for #ident #generics {
+                        type Output = Self;
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(self, rhs: Self) -> Self {
+                            let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                            unsafe {
+                                self::ffi::#cxx_operator(this.as_mut_ptr(), &self, &rhs);
+                                this.assume_init()
+                            }
+                        }
+                    }
+                },
+                (OperatorKind::Binary, false) => syn::parse_quote! {
+                    impl #operation_generics_binder ::core::ops::#rust_trait for &'op #ident #generics {
+                        type Output = #operation #operation_generics;
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(self, rhs: Self) -> Self::Output {
+                            #operation {
+                                operator: self::ffi::#cxx_operator,
+                                lhs: self,
+                                rhs,
+                            }
+                        }
+                    }
+                },
+                (OperatorKind::Unary, true) => syn::parse_quote! {
+                    impl #generics_binder ::core::ops::#rust_trait for #ident #generics {
+                        type Output = Self;
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(self) -> Self {
+                            let mut this = ::core::mem::MaybeUninit::<Self>::uninit();
+                            unsafe {
+                                self::ffi::#cxx_operator(this.as_mut_ptr(), &self);
+                                this.assume_init()
+                            }
+                        }
+                    }
+                },
+                (OperatorKind::Unary, false) => syn::parse_quote! {
+                    impl #operation_generics_binder ::core::ops::#rust_trait for &'op #ident #generics {
+                        type Output = #operation #operation_generics;
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(self) -> Self::Output {
+                            #operation {
+                                operator: self::ffi::#cxx_operator,
+                                arg: self,
+                            }
+                        }
+                    }
+                },
+                (OperatorKind::CompoundAssignment, true) => syn::parse_quote! {
+                    impl #generics_binder ::core::ops::#rust_trait for #ident #generics {
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(&mut self, rhs: Self) {
+                            self::ffi::#cxx_operator(::core::pin::Pin::new(self), &rhs);
+                        }
+                    }
+                },
+                (OperatorKind::CompoundAssignment, false) => syn::parse_quote! {
+                    impl #operation_generics_binder ::core::ops::#rust_trait<&'op #ident #generics>
+                        for ::core::pin::Pin<&mut #ident #generics>
+                    {
+                        #[doc = #doc]
+                        #[inline]
+                        fn #rust_method(&mut self, rhs: &'op #ident #generics) {
+                            self::ffi::#cxx_operator(self.as_mut(), rhs);
+                        }
+                    }
+                },
             }
         })
-    } else {
-        None
-    }
+        .collect()
 }
 
 #[cfg(feature = "alloc")]
-fn emit_impl_hash(
+fn emit_impl_methods(
     info: &CxxAutoArtifactInfo,
     ident: &syn::Ident,
     generics_binder: &syn::Generics,
     generics: &syn::Generics,
 ) -> Option<syn::ItemImpl> {
-    if info.is_rust_hash {
-        Some(syn::parse_quote! {
-            impl #generics_binder ::core::hash::Hash for #ident #generics {
+    if info.methods.is_empty() {
+        return None;
+    }
+    let span = Span::call_site();
+    let methods = info.methods.iter().map(|method| -> syn::ImplItemFn {
+        let rust_name = syn::Ident::new(method.rust_name(), span);
+        let cxx_method = syn::Ident::new(&::alloc::format!("cxx_method_{}", method.rust_name()), span);
+        let args = method.args.iter().map(crate::CxxAutoArg::emit_fn_arg);
+        let arg_idents = method.args.iter().map(crate::CxxAutoArg::emit_ident);
+        let returns = method.emit_return_type();
+        if method.is_const {
+            syn::parse_quote! {
                 #[inline]
-                fn hash<H>(&self, state: &mut H)
-                where
-                    H: ::core::hash::Hasher,
-                {
-                    let hash = self::ffi::cxx_hash(self);
-                    state.write_usize(hash);
+                pub fn #rust_name(&self, #(#args),*) #returns {
+                    self.#cxx_method(#(#arg_idents),*)
                 }
             }
-        })
-    } else {
-        None
-    }
+        } else if info.is_rust_unpin {
+            syn::parse_quote! {
+                #[inline]
+                pub fn #rust_name(&mut self, #(#args),*) #returns {
+                    ::core::pin::Pin::new(self).#cxx_method(#(#arg_idents),*)
+                }
+            }
+        } else {
+            syn::parse_quote! {
+                #[inline]
+                pub fn #rust_name(self: ::core::pin::Pin<&mut Self>, #(#args),*) #returns {
+                    self.#cxx_method(#(#arg_idents),*)
+                }
+            }
+        }
+    });
+    Some(syn::parse_quote! {
+        impl #generics_binder #ident #generics {
+            #(#methods)*
+        }
+    })
 }
 
+#[allow(clippy::too_many_lines)]
 #[cfg(feature = "alloc")]
 fn emit_info_test_module(
     info: &CxxAutoArtifactInfo,
@@ -629,6 +2570,20 @@ fn emit_info_test_module(
         let all_static = true;
         &emit_generics(info, all_static)
     };
+    let static_assert_is_clone: Option<syn::ItemMacro> = if has_clone(info) {
+        Some(syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(#ident #generics: ::core::clone::Clone);
+        ))
+    } else {
+        None
+    };
+    let static_assert_is_default: Option<syn::ItemMacro> = if info.is_rust_default && info.is_rust_unpin {
+        Some(syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(#ident #generics: ::core::default::Default);
+        ))
+    } else {
+        None
+    };
     let static_assert_is_copy: Option<syn::ItemMacro> = if info.is_rust_copy {
         Some(syn::parse_quote!(
             ::static_assertions::assert_impl_all!(#ident #generics: ::core::marker::Copy);
@@ -636,6 +2591,85 @@ fn emit_info_test_module(
     } else {
         None
     };
+    let static_assert_is_from_iterator: Option<syn::ItemMacro> = match &info.range_item {
+        Some(range_item) if has_from_iterator(info) && info.is_rust_unpin => {
+            let item = range_item.emit_path();
+            Some(syn::parse_quote!(
+                ::static_assertions::assert_impl_all!(#ident #generics: ::core::iter::FromIterator<#item>);
+            ))
+        },
+        _ => None,
+    };
+    let static_assert_is_from_str: Option<syn::ItemMacro> = if info.is_rust_from_str && info.is_rust_unpin {
+        Some(syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(#ident #generics: ::core::str::FromStr);
+        ))
+    } else {
+        None
+    };
+    let static_asserts_conversions = info
+        .conversions
+        .iter()
+        .filter(|_| info.is_rust_unpin)
+        .map(|(conversion, _, is_nothrow)| -> syn::ItemMacro {
+            let from = conversion.from.emit_path();
+            let that: syn::Type = if conversion.from.is_primitive() {
+                syn::parse_quote!(#from)
+            } else {
+                syn::parse_quote!(&'static #from)
+            };
+            if *is_nothrow {
+                syn::parse_quote!(
+                    ::static_assertions::assert_impl_all!(#ident #generics: ::core::convert::From<#that>);
+                )
+            } else {
+                syn::parse_quote!(
+                    ::static_assertions::assert_impl_all!(#ident #generics: ::core::convert::TryFrom<#that>);
+                )
+            }
+        });
+    let static_asserts_comparisons = info
+        .comparisons
+        .iter()
+        .flat_map(|(comparison, _, cxx_is_partially_ordered)| {
+            let rhs = comparison.emit_path();
+            let partial_eq: syn::ItemMacro = syn::parse_quote!(
+                ::static_assertions::assert_impl_all!(#ident #generics: ::core::cmp::PartialEq<#rhs>);
+            );
+            let partial_ord: Option<syn::ItemMacro> = if *cxx_is_partially_ordered {
+                Some(syn::parse_quote!(
+                    ::static_assertions::assert_impl_all!(#ident #generics: ::core::cmp::PartialOrd<#rhs>);
+                ))
+            } else {
+                None
+            };
+            ::core::iter::once(partial_eq).chain(partial_ord)
+        });
+    let static_assert_is_into_iterator: Option<syn::ItemMacro> = if info.range_item.is_some() {
+        Some(syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(&'static #ident #generics: ::core::iter::IntoIterator);
+        ))
+    } else {
+        None
+    };
+    let static_assert_is_index: Option<syn::ItemMacro> = match &info.index {
+        Some(index) => {
+            let ty = index.emit_type();
+            Some(syn::parse_quote!(
+                ::static_assertions::assert_impl_all!(#ident #generics: ::core::ops::Index<#ty>);
+            ))
+        },
+        None => None,
+    };
+    let static_assert_is_index_mut: Option<syn::ItemMacro> = match &info.index {
+        Some(index) if has_index_mut(info) => {
+            let ty = index.emit_type();
+            Some(syn::parse_quote!(
+                ::static_assertions::assert_impl_all!(#ident #generics: ::core::ops::IndexMut<#ty>);
+            ))
+        },
+        _ => None,
+    };
     let static_assert_is_unpin: Option<syn::ItemMacro> = if info.is_rust_unpin {
         Some(syn::parse_quote!(
             ::static_assertions::assert_impl_all!(#ident #generics: ::core::marker::Unpin);
@@ -643,6 +2677,59 @@ fn emit_info_test_module(
     } else {
         None
     };
+    let static_assert_is_send: syn::ItemMacro = if info.is_rust_send {
+        syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(#ident #generics: ::core::marker::Send);
+        )
+    } else {
+        syn::parse_quote!(
+            ::static_assertions::assert_not_impl_any!(#ident #generics: ::core::marker::Send);
+        )
+    };
+    let static_assert_is_sync: syn::ItemMacro = if info.is_rust_sync {
+        syn::parse_quote!(
+            ::static_assertions::assert_impl_all!(#ident #generics: ::core::marker::Sync);
+        )
+    } else {
+        syn::parse_quote!(
+            ::static_assertions::assert_not_impl_any!(#ident #generics: ::core::marker::Sync);
+        )
+    };
+    let test_cxx_field_layouts: Option<syn::ItemFn> = if info.fields.is_empty() {
+        None
+    } else {
+        let has_typed_fields = has_typed_fields(info);
+        let asserts = info.fields.iter().map(|(field, offset, size)| -> syn::Block {
+            let rust_name = field.emit_ident();
+            let ty = emit_field_type_all_static(info, field);
+            let offset = proc_macro2::Literal::usize_unsuffixed(*offset);
+            let size = proc_macro2::Literal::usize_unsuffixed(*size);
+            let assert_offset: Option<syn::Stmt> = if has_typed_fields {
+                Some(syn::parse_quote! {
+                    {
+                        let this = ::core::mem::MaybeUninit::<#ident #generics>::uninit();
+                        let base = this.as_ptr();
+                        let field = unsafe { ::core::ptr::addr_of!((*base).#rust_name) };
+                        ::core::assert_eq!(field as usize - base as usize, #offset);
+                    }
+                })
+            } else {
+                None
+            };
+            syn::parse_quote!({
+                #assert_offset
+                ::core::assert_eq!(::core::mem::size_of::<#ty>(), #size);
+                ::core::assert_eq!(#offset % ::core::mem::align_of::<#ty>(), 0);
+            })
+        });
+        Some(syn::parse_quote! {
+            #[test]
+            fn cxx_field_layouts() {
+                #(#asserts)*
+            }
+        })
+    };
+    let item_laws_module = emit_info_laws_module(info, ident);
     syn::parse_quote! {
         #[cfg(test)]
         mod info {
@@ -657,16 +2744,218 @@ fn emit_info_test_module(
                 fn cxx_abi_size() {
                     ::core::assert_eq!(::core::mem::size_of::<#ident #generics>(), #size)
                 }
+                #test_cxx_field_layouts
+                #static_assert_is_clone
                 #static_assert_is_copy
+                #static_assert_is_default
                 #static_assert_is_unpin
+                #static_assert_is_send
+                #static_assert_is_sync
+                #static_assert_is_into_iterator
+                #static_assert_is_from_iterator
+                #static_assert_is_from_str
+                #(#static_asserts_conversions)*
+                #(#static_asserts_comparisons)*
+                #static_assert_is_index
+                #static_assert_is_index_mut
             }
+            #item_laws_module
         }
     }
 }
 
+// NOTE: each sample is borrowed in place, so non-`Unpin` types can be sampled through, e.g., a pinned box
+#[allow(clippy::too_many_lines)]
+#[cfg(feature = "alloc")]
+fn emit_info_laws_module(info: &CxxAutoArtifactInfo, ident: &syn::Ident) -> Option<syn::ItemMod> {
+    if info.samples.is_empty() {
+        return None;
+    }
+    let (_, generics) = {
+        let all_static = true;
+        &emit_generics(info, all_static)
+    };
+    let count = proc_macro2::Literal::usize_unsuffixed(info.samples.len());
+    let samples = info.samples.iter().map(|sample| -> syn::Expr {
+        syn::parse_str(sample)
+            .unwrap_or_else(|err| panic!("cxx-auto: invalid Rust sample expression `{sample}`: {err}"))
+    });
+    let let_samples: syn::Stmt = syn::parse_quote! {
+        let samples: [&#ident #generics; #count] = [#(&(#samples)),*];
+    };
+    let test_partial_eq_laws: Option<syn::ItemFn> = if info.is_rust_partial_eq {
+        let assert_reflexive: Option<syn::Stmt> = if info.is_rust_eq {
+            Some(syn::parse_quote! {
+                ::core::assert!(::core::cmp::PartialEq::eq(a, a), "`eq` is not reflexive for sample {i}");
+            })
+        } else {
+            None
+        };
+        Some(syn::parse_quote! {
+            #[test]
+            fn cxx_partial_eq_laws() {
+                #let_samples
+                for (i, a) in samples.iter().copied().enumerate() {
+                    #assert_reflexive
+                    for (j, b) in samples.iter().copied().enumerate() {
+                        let eq = ::core::cmp::PartialEq::eq(a, b);
+                        ::core::assert_eq!(
+                            eq,
+                            !::core::cmp::PartialEq::ne(a, b),
+                            "`eq` and `ne` disagree for samples {i} and {j}"
+                        );
+                        ::core::assert_eq!(
+                            eq,
+                            ::core::cmp::PartialEq::eq(b, a),
+                            "`eq` is not symmetric for samples {i} and {j}"
+                        );
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let test_partial_ord_laws: Option<syn::ItemFn> = if info.is_rust_partial_ord {
+        let assert_eq: Option<syn::Stmt> = if info.is_rust_partial_eq {
+            Some(syn::parse_quote! {
+                ::core::assert_eq!(
+                    ordering == Some(::core::cmp::Ordering::Equal),
+                    ::core::cmp::PartialEq::eq(a, b),
+                    "`partial_cmp` and `eq` disagree for samples {i} and {j}"
+                );
+            })
+        } else {
+            None
+        };
+        Some(syn::parse_quote! {
+            #[test]
+            fn cxx_partial_ord_laws() {
+                #let_samples
+                for (i, a) in samples.iter().copied().enumerate() {
+                    for (j, b) in samples.iter().copied().enumerate() {
+                        let ordering = ::core::cmp::PartialOrd::partial_cmp(a, b);
+                        #assert_eq
+                        ::core::assert_eq!(
+                            ::core::cmp::PartialOrd::lt(a, b),
+                            ordering.is_some_and(::core::cmp::Ordering::is_lt),
+                            "`partial_cmp` and `lt` disagree for samples {i} and {j}"
+                        );
+                        ::core::assert_eq!(
+                            ::core::cmp::PartialOrd::le(a, b),
+                            ordering.is_some_and(::core::cmp::Ordering::is_le),
+                            "`partial_cmp` and `le` disagree for samples {i} and {j}"
+                        );
+                        ::core::assert_eq!(
+                            ::core::cmp::PartialOrd::gt(a, b),
+                            ordering.is_some_and(::core::cmp::Ordering::is_gt),
+                            "`partial_cmp` and `gt` disagree for samples {i} and {j}"
+                        );
+                        ::core::assert_eq!(
+                            ::core::cmp::PartialOrd::ge(a, b),
+                            ordering.is_some_and(::core::cmp::Ordering::is_ge),
+                            "`partial_cmp` and `ge` disagree for samples {i} and {j}"
+                        );
+                        ::core::assert_eq!(
+                            ordering,
+                            ::core::cmp::PartialOrd::partial_cmp(b, a).map(::core::cmp::Ordering::reverse),
+                            "`partial_cmp` is not antisymmetric for samples {i} and {j}"
+                        );
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+    // NOTE: `cmp` is checked against the C++ operators directly, since the generated `partial_cmp` may itself be
+    // derived from `cmp`
+    let test_ord_laws: Option<syn::ItemFn> = if info.is_rust_ord {
+        let assert_less_than: Option<syn::Stmt> = if info.cxx_has_operator_less_than {
+            Some(syn::parse_quote! {
+                ::core::assert_eq!(
+                    ordering == ::core::cmp::Ordering::Less,
+                    ffi::cxx_operator_less_than(a, b),
+                    "`cmp` and `operator<` disagree for samples {i} and {j}"
+                );
+            })
+        } else {
+            None
+        };
+        Some(syn::parse_quote! {
+            #[test]
+            fn cxx_ord_laws() {
+                #let_samples
+                for (i, a) in samples.iter().copied().enumerate() {
+                    for (j, b) in samples.iter().copied().enumerate() {
+                        let ordering = ::core::cmp::Ord::cmp(a, b);
+                        #assert_less_than
+                        ::core::assert_eq!(
+                            ordering == ::core::cmp::Ordering::Equal,
+                            ffi::cxx_operator_equal(a, b),
+                            "`cmp` and `operator==` disagree for samples {i} and {j}"
+                        );
+                        for (k, c) in samples.iter().copied().enumerate() {
+                            if ordering == ::core::cmp::Ord::cmp(b, c) {
+                                ::core::assert_eq!(
+                                    ordering,
+                                    ::core::cmp::Ord::cmp(a, c),
+                                    "`cmp` is not transitive for samples {i}, {j} and {k}"
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let test_hash_laws: Option<syn::ItemFn> = if info.is_rust_hash && info.is_rust_partial_eq {
+        Some(syn::parse_quote! {
+            #[test]
+            fn cxx_hash_laws() {
+                #let_samples
+                let state = ::std::collections::hash_map::RandomState::new();
+                for (i, a) in samples.iter().copied().enumerate() {
+                    for (j, b) in samples.iter().copied().enumerate() {
+                        if ::core::cmp::PartialEq::eq(a, b) {
+                            ::core::assert_eq!(
+                                ::core::hash::BuildHasher::hash_one(&state, a),
+                                ::core::hash::BuildHasher::hash_one(&state, b),
+                                "equal samples {i} and {j} hash differently"
+                            );
+                        }
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+    if test_partial_eq_laws.is_none()
+        && test_partial_ord_laws.is_none()
+        && test_ord_laws.is_none()
+        && test_hash_laws.is_none()
+    {
+        return None;
+    }
+    Some(syn::parse_quote! {
+        mod laws {
+            use super::*;
+            #test_partial_eq_laws
+            #test_partial_ord_laws
+            #test_ord_laws
+            #test_hash_laws
+        }
+    })
+}
+
 #[allow(clippy::too_many_lines)]
 #[cfg(feature = "alloc")]
 fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, generics: &syn::Generics) -> syn::ItemMod {
+    let align = proc_macro2::Literal::usize_unsuffixed(info.align);
+    let size = proc_macro2::Literal::usize_unsuffixed(info.size);
     let cxx_include = &info.cxx_include;
     let cxx_namespace = &info.cxx_namespace;
     let cxx_name = &info.cxx_name;
@@ -677,6 +2966,66 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
     } else {
         None
     };
+    let cxx_copy_assign: Option<syn::ForeignItemFn> = if info.cxx_is_copy_assignable {
+        Some(syn::parse_quote! {
+            fn cxx_copy_assign #generics (This: Pin<&mut #ident #generics>, that: &#ident #generics);
+        })
+    } else {
+        None
+    };
+    let cxx_move_assign: Option<syn::ForeignItemFn> = if info.cxx_is_move_assignable {
+        Some(syn::parse_quote! {
+            unsafe fn cxx_move_assign #generics (This: Pin<&mut #ident #generics>, that: *mut #ident #generics);
+        })
+    } else {
+        None
+    };
+    let mut cxx_smart_ptrs = ::alloc::vec::Vec::<syn::ForeignItemFn>::new();
+    if has_smart_ptrs(info) {
+        for (name, ptr) in [("unique", "UniquePtr"), ("shared", "SharedPtr")] {
+            let ptr = syn::Ident::new(ptr, Span::call_site());
+            let cxx_default = syn::Ident::new(&::alloc::format!("cxx_default_{name}"), Span::call_site());
+            let cxx_copy = syn::Ident::new(&::alloc::format!("cxx_copy_{name}"), Span::call_site());
+            let cxx_move = syn::Ident::new(&::alloc::format!("cxx_move_{name}"), Span::call_site());
+            if info.is_rust_default {
+                cxx_smart_ptrs.push(syn::parse_quote! {
+                    fn #cxx_default #generics () -> #ptr<#ident #generics>;
+                });
+            }
+            if info.is_rust_copy_new {
+                cxx_smart_ptrs.push(syn::parse_quote! {
+                    fn #cxx_copy #generics (that: &#ident #generics) -> #ptr<#ident #generics>;
+                });
+            }
+            if info.is_rust_move_new {
+                cxx_smart_ptrs.push(syn::parse_quote! {
+                    unsafe fn #cxx_move #generics (that: *mut #ident #generics) -> #ptr<#ident #generics>;
+                });
+            }
+        }
+    }
+    let impls_smart_ptr: ::alloc::vec::Vec<syn::ItemImpl> = if has_smart_ptrs(info) {
+        ::alloc::vec![
+            syn::parse_quote! {
+                impl #generics UniquePtr<#ident #generics> {}
+            },
+            syn::parse_quote! {
+                impl #generics SharedPtr<#ident #generics> {}
+            },
+            syn::parse_quote! {
+                impl #generics WeakPtr<#ident #generics> {}
+            },
+        ]
+    } else {
+        ::alloc::vec![]
+    };
+    let impl_cxx_vector: Option<syn::ItemImpl> = if has_cxx_vector(info) {
+        Some(syn::parse_quote! {
+            impl #generics CxxVector<#ident #generics> {}
+        })
+    } else {
+        None
+    };
     let cxx_move_new: Option<syn::ForeignItemFn> = if info.is_rust_move_new {
         Some(syn::parse_quote! {
             unsafe fn cxx_move_new #generics (This: *mut #ident #generics, that: *mut #ident #generics);
@@ -698,14 +3047,14 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
     } else {
         None
     };
-    let cxx_operator_equal: Option<syn::ForeignItemFn> = if info.is_rust_eq {
+    let cxx_operator_equal: Option<syn::ForeignItemFn> = if info.is_rust_partial_eq {
         Some(syn::parse_quote! {
             fn cxx_operator_equal #generics (This: & #ident #generics, That: & #ident #generics) -> bool;
         })
     } else {
         None
     };
-    let cxx_operator_not_equal: Option<syn::ForeignItemFn> = if info.is_rust_eq {
+    let cxx_operator_not_equal: Option<syn::ForeignItemFn> = if info.is_rust_partial_eq && info.cxx_has_operator_not_equal {
         Some(syn::parse_quote! {
             fn cxx_operator_not_equal #generics (This: & #ident #generics, That: & #ident #generics) -> bool;
         })
@@ -743,7 +3092,21 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
     };
     let cxx_operator_three_way_comparison: Option<syn::ForeignItemFn> = if info.is_rust_partial_ord {
         Some(syn::parse_quote! {
-            fn cxx_operator_three_way_comparison #generics (This: & #ident #generics, That: & #ident #generics) -> i8;
+            fn cxx_operator_three_way_comparison #generics (This: & #ident #generics, That: & #ident #generics)
+                -> CxxOrdering;
+        })
+    } else {
+        None
+    };
+    let is_partially_ordered_with_any = info
+        .comparisons
+        .iter()
+        .any(|(.., cxx_is_partially_ordered)| *cxx_is_partially_ordered);
+    let cxx_ordering: Option<syn::ForeignItem> = if info.is_rust_partial_ord || is_partially_ordered_with_any {
+        Some(syn::parse_quote! {
+            #[namespace = "cxx_auto"]
+            #[cxx_name = "cxx_ordering"]
+            type CxxOrdering = ::cxx_auto::cmp::CxxOrdering;
         })
     } else {
         None
@@ -755,20 +3118,182 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
     } else {
         None
     };
-    let cxx_debug: Option<syn::ForeignItemFn> = if info.is_rust_debug {
+    let mut fmts = ::alloc::vec::Vec::new();
+    if info.is_rust_debug {
+        fmts.push(debug_fmt(info));
+    }
+    if info.is_rust_display && !fmts.contains(&display_fmt(info)) {
+        fmts.push(display_fmt(info));
+    }
+    let cxx_fmts = fmts.into_iter().map(|fmt| -> syn::ForeignItemFn {
+        let cxx_fmt = fmt.emit_cxx_ident();
+        if fmt == crate::CxxAutoFmtSource::Formatter {
+            syn::parse_quote! {
+                fn #cxx_fmt #generics (This: & #ident #generics, spec: &str, writer: &mut CxxFmtWriter<'_, '_>) -> u8;
+            }
+        } else {
+            syn::parse_quote! {
+                fn #cxx_fmt #generics (This: & #ident #generics, writer: &mut CxxFmtWriter<'_, '_>) -> bool;
+            }
+        }
+    });
+    let item_foreign_mod_cxx_fmt_writer: Option<syn::ItemForeignMod> = if info.is_rust_debug || info.is_rust_display {
         Some(syn::parse_quote! {
-            fn cxx_debug #generics (This: & #ident #generics) -> String;
+            #[namespace = #cxx_namespace]
+            extern "Rust" {
+                type CxxFmtWriter<'a, 'f>;
+                fn write(self: &mut CxxFmtWriter<'_, '_>, bytes: &[u8]) -> bool;
+            }
         })
     } else {
         None
     };
-    let cxx_display: Option<syn::ForeignItemFn> = if info.is_rust_display {
+    let cxx_placement_news = info.constructors.iter().map(|constructor| -> syn::ForeignItemFn {
+        let cxx_placement_new = syn::Ident::new(
+            &::alloc::format!("cxx_placement_new_{}", constructor.rust_name),
+            Span::call_site(),
+        );
+        let args = constructor.args.iter().map(crate::CxxAutoArg::emit_fn_arg);
+        syn::parse_quote! {
+            #[cxx_name = "cxx_placement_new"]
+            unsafe fn #cxx_placement_new #generics (This: *mut #ident #generics, #(#args),*);
+        }
+    });
+    let cxx_type_refs = emit_foreign_item_type_refs(info);
+    let cxx_range_cursor: ::alloc::vec::Vec<syn::ForeignItem> = if let Some(range_item) = &info.range_item {
+        let item = range_item.emit_ident();
+        ::alloc::vec![
+            syn::parse_quote! {
+                #[cxx_name = "cxx_range_cursor"]
+                type CxxRangeCursor;
+            },
+            syn::parse_quote! {
+                fn cxx_range_cursor_new #generics (This: & #ident #generics) -> UniquePtr<CxxRangeCursor>;
+            },
+            syn::parse_quote! {
+                fn cxx_range_cursor_next(cursor: Pin<&mut CxxRangeCursor>) -> *const #item;
+            },
+        ]
+    } else {
+        ::alloc::vec![]
+    };
+    let cxx_from_iter_new: Option<syn::ForeignItemFn> = match &info.range_item {
+        Some(range_item) if has_from_iterator(info) => {
+            let item = range_item.emit_ident();
+            Some(syn::parse_quote! {
+                unsafe fn cxx_from_iter_new #generics (This: *mut #ident #generics, first: *const #item, last: *const #item);
+            })
+        },
+        _ => None,
+    };
+    let cxx_convert_news = info.conversions.iter().enumerate().map(|(index, (conversion, ..))| -> syn::ForeignItemFn {
+        let cxx_convert_new = syn::Ident::new(&::alloc::format!("cxx_convert_new_{index}"), Span::call_site());
+        let from = conversion.from.emit_ident();
+        let that: syn::Type = if conversion.from.is_primitive() {
+            syn::parse_quote!(#from)
+        } else {
+            syn::parse_quote!(&#from)
+        };
+        syn::parse_quote! {
+            #[cxx_name = "cxx_convert_new"]
+            unsafe fn #cxx_convert_new #generics (This: *mut #ident #generics, that: #that) -> bool;
+        }
+    });
+    let cxx_operator_withs = info.comparisons.iter().enumerate().flat_map(
+        |(index, (comparison, _, cxx_is_partially_ordered))| {
+            let cxx_operator_equal_with =
+                syn::Ident::new(&::alloc::format!("cxx_operator_equal_with_{index}"), Span::call_site());
+            let cxx_operator_three_way_comparison_with = syn::Ident::new(
+                &::alloc::format!("cxx_operator_three_way_comparison_with_{index}"),
+                Span::call_site(),
+            );
+            let rhs = comparison.emit_ident();
+            let that: syn::Type = if comparison.is_primitive() {
+                syn::parse_quote!(#rhs)
+            } else {
+                syn::parse_quote!(&#rhs)
+            };
+            let cxx_operator_equal_with: syn::ForeignItemFn = syn::parse_quote! {
+                #[cxx_name = "cxx_operator_equal_with"]
+                fn #cxx_operator_equal_with #generics (This: & #ident #generics, That: #that) -> bool;
+            };
+            let cxx_operator_three_way_comparison_with: Option<syn::ForeignItemFn> = if *cxx_is_partially_ordered {
+                Some(syn::parse_quote! {
+                    #[cxx_name = "cxx_operator_three_way_comparison_with"]
+                    fn #cxx_operator_three_way_comparison_with #generics (This: & #ident #generics, That: #that)
+                        -> CxxOrdering;
+                })
+            } else {
+                None
+            };
+            ::core::iter::once(cxx_operator_equal_with).chain(cxx_operator_three_way_comparison_with)
+        },
+    );
+    let cxx_parse_new: Option<syn::ForeignItemFn> = if info.is_rust_from_str {
         Some(syn::parse_quote! {
-            fn cxx_display #generics (This: & #ident #generics) -> String;
+            unsafe fn cxx_parse_new #generics (This: *mut #ident #generics, text: &str) -> u8;
         })
     } else {
         None
     };
+    let cxx_subscripts: ::alloc::vec::Vec<syn::ForeignItemFn> = if let Some(index) = &info.index {
+        let ty = index.emit_type();
+        let output = index.output.emit_ident();
+        // NOTE: the element borrow must be tied to `This` explicitly, since lifetime elision is ambiguous when the
+        // type itself has lifetime parameters
+        let (this_generics, _) = emit_generics_with_lifetime(generics, generics, "this");
+        let mut items: ::alloc::vec::Vec<syn::ForeignItemFn> = ::alloc::vec![
+            syn::parse_quote! {
+                fn cxx_operator_subscript_const #this_generics (This: &'this #ident #generics, index: #ty) -> &'this #output;
+            },
+            syn::parse_quote! {
+                fn cxx_size #generics (This: & #ident #generics) -> usize;
+            },
+        ];
+        if info.cxx_has_operator_subscript {
+            let returns: syn::Type = if index.output.cxx_name.is_some() {
+                syn::parse_quote!(Pin<&'this mut #output>)
+            } else {
+                syn::parse_quote!(&'this mut #output)
+            };
+            items.push(syn::parse_quote! {
+                fn cxx_operator_subscript #this_generics (This: Pin<&'this mut #ident #generics>, index: #ty) -> #returns;
+            });
+        }
+        items
+    } else {
+        ::alloc::vec![]
+    };
+    let cxx_operators = detected_operators(info).map(|(kind, cxx_operator, ..)| -> syn::ForeignItemFn {
+        let cxx_operator = emit_cxx_operator_ident(cxx_operator);
+        match kind {
+            OperatorKind::Binary => syn::parse_quote! {
+                unsafe fn #cxx_operator #generics (This: *mut #ident #generics, lhs: & #ident #generics, rhs: & #ident #generics);
+            },
+            OperatorKind::Unary => syn::parse_quote! {
+                unsafe fn #cxx_operator #generics (This: *mut #ident #generics, arg: & #ident #generics);
+            },
+            OperatorKind::CompoundAssignment => syn::parse_quote! {
+                fn #cxx_operator #generics (This: Pin<&mut #ident #generics>, That: & #ident #generics);
+            },
+        }
+    });
+    let cxx_methods = info.methods.iter().map(|method| -> syn::ForeignItemFn {
+        let cxx_name = method.name;
+        let cxx_method = syn::Ident::new(&::alloc::format!("cxx_method_{}", method.rust_name()), Span::call_site());
+        let receiver: syn::Type = if method.is_const {
+            syn::parse_quote!(& #ident #generics)
+        } else {
+            syn::parse_quote!(Pin<&mut #ident #generics>)
+        };
+        let args = method.args.iter().map(crate::CxxAutoArg::emit_fn_arg);
+        let returns = method.emit_return_type();
+        syn::parse_quote! {
+            #[cxx_name = #cxx_name]
+            #[doc(hidden)]
+            fn #cxx_method #generics (self: #receiver, #(#args),*) #returns;
+        }
+    });
     syn::parse_quote! {
         #[cxx::bridge]
         pub(crate) mod ffi {
@@ -780,9 +3305,15 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
                 #[cxx_name = #cxx_name]
                 #[allow(unused)]
                 type #ident #generics = super :: #ident #generics;
+                #[allow(unused)]
+                fn cxx_assert_abi_layout(size: &[u8; #size], align: &[u8; #align]);
                 #cxx_copy_new
+                #cxx_copy_assign
                 #cxx_move_new
+                #cxx_move_assign
                 #cxx_default_new
+                #(#cxx_smart_ptrs)*
+                #(#cxx_placement_news)*
                 #cxx_destruct
                 #cxx_operator_equal
                 #cxx_operator_not_equal
@@ -790,13 +3321,48 @@ fn emit_item_mod_cxx_bridge(info: &CxxAutoArtifactInfo, ident: &syn::Ident, gene
                 #cxx_operator_less_than_or_equal
                 #cxx_operator_greater_than
                 #cxx_operator_greater_than_or_equal
+                #cxx_ordering
                 #cxx_operator_three_way_comparison
+                #(#cxx_operators)*
                 #cxx_hash
-                #cxx_debug
-                #cxx_display
+                #(#cxx_fmts)*
+                #(#cxx_methods)*
+                #(#cxx_type_refs)*
+                #(#cxx_range_cursor)*
+                #cxx_from_iter_new
+                #cxx_parse_new
+                #(#cxx_convert_news)*
+                #(#cxx_operator_withs)*
+                #(#cxx_subscripts)*
             }
+
+            #item_foreign_mod_cxx_fmt_writer
+
+            #(#impls_smart_ptr)*
+            #impl_cxx_vector
+        }
+    }
+}
+
+// NOTE: the same type may be referenced more than once, but can only be declared once in the bridge
+#[cfg(feature = "alloc")]
+fn emit_foreign_item_type_refs(info: &CxxAutoArtifactInfo) -> ::alloc::vec::Vec<syn::ForeignItem> {
+    let mut rust_paths = ::alloc::vec::Vec::<&str>::new();
+    let mut items = ::alloc::vec::Vec::new();
+    let type_refs = info
+        .range_item
+        .iter()
+        .chain(info.index.iter().map(|index| &index.output))
+        .chain(info.conversions.iter().map(|(conversion, ..)| &conversion.from))
+        .chain(info.comparisons.iter().map(|(comparison, ..)| comparison));
+    for type_ref in type_refs {
+        if rust_paths.contains(&type_ref.rust_path) {
+            continue;
         }
+        rust_paths.push(type_ref.rust_path);
+        items.extend(type_ref.emit_foreign_item_type());
     }
+    items
 }
 
 fn emit_refs_from_lifetimes(generics: &syn::Generics) -> Punctuated<syn::Type, syn::Token![,]> {
@@ -816,6 +3382,50 @@ fn emit_ref_type_from_lifetime(generic_param: &syn::GenericParam) -> Option<syn:
     }
 }
 
+// NOTE: fields can only be exposed by value when Rust is allowed to move the type, otherwise they are reached
+// through accessors
+#[cfg(feature = "alloc")]
+fn has_typed_fields(info: &CxxAutoArtifactInfo) -> bool {
+    !info.fields.is_empty() && info.is_rust_cxx_extern_type_trivial
+}
+
+#[cfg(feature = "alloc")]
+fn sorted_fields(info: &CxxAutoArtifactInfo) -> ::alloc::vec::Vec<(&crate::CxxAutoField<'static>, usize, usize)> {
+    let mut fields = info
+        .fields
+        .iter()
+        .map(|(field, offset, size)| (field, *offset, *size))
+        .collect::<::alloc::vec::Vec<_>>();
+    fields.sort_by_key(|&(_, offset, _)| offset);
+    fields
+}
+
+#[cfg(feature = "alloc")]
+fn fields_typed_layout(info: &CxxAutoArtifactInfo) -> ::alloc::vec::Vec<syn::Field> {
+    let mut fields = ::alloc::vec::Vec::new();
+    let mut cursor = 0;
+    for (field, offset, size) in sorted_fields(info) {
+        fields.extend(field_padding(cursor, offset));
+        let mut typed = emit_field(field.rust_name(), field.emit_type());
+        typed.vis = syn::parse_quote!(pub);
+        fields.push(typed);
+        cursor = offset + size;
+    }
+    fields.extend(field_padding(cursor, info.size));
+    fields
+}
+
+#[cfg(feature = "alloc")]
+fn field_padding(start: usize, end: usize) -> Option<syn::Field> {
+    if start < end {
+        let name = ::alloc::format!("_layout_{start}");
+        let size = proc_macro2::Literal::usize_unsuffixed(end - start);
+        Some(emit_field(&name, syn::parse_quote!([u8; #size])))
+    } else {
+        None
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn field_layout(size: &proc_macro2::Literal) -> syn::Field {
     let name = "_layout";
@@ -823,15 +3433,22 @@ fn field_layout(size: &proc_macro2::Literal) -> syn::Field {
     emit_field(name, ty)
 }
 
+// NOTE: `Sync` without `Send` has no marker type in `core`, so that case removes both auto-traits and relies on the
+// explicit `unsafe impl Sync` from `emit_impl_sync`
 #[cfg(feature = "alloc")]
-fn field_neither_send_nor_sync(info: &CxxAutoArtifactInfo) -> Option<syn::Field> {
-    let is_neither_send_nor_sync = !info.is_rust_send && !info.is_rust_sync;
-    if is_neither_send_nor_sync {
-        let name = "_neither_send_nor_sync";
-        let ty = syn::parse_quote!(::core::marker::PhantomData<[*const u8; 0]>);
-        Some(emit_field(name, ty))
-    } else {
-        None
+fn field_send_sync_marker(info: &CxxAutoArtifactInfo) -> Option<syn::Field> {
+    match (info.is_rust_send, info.is_rust_sync) {
+        (true, true) => None,
+        (true, false) => {
+            let name = "_not_sync";
+            let ty = syn::parse_quote!(::core::marker::PhantomData<::core::cell::Cell<()>>);
+            Some(emit_field(name, ty))
+        },
+        (false, _) => {
+            let name = "_neither_send_nor_sync";
+            let ty = syn::parse_quote!(::core::marker::PhantomData<[*const u8; 0]>);
+            Some(emit_field(name, ty))
+        },
     }
 }
 
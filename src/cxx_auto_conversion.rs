@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+// NOTE: a candidate implicit C++ conversion from `from` into the entry type
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoConversion<'ctx> {
+    pub from: crate::CxxAutoTypeRef<'ctx>,
+    pub rust_name: Option<&'ctx str>,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoConversion<'_> {
+    /// The name of the generated constructor, which defaults to `from_` followed by the snake-cased source type.
+    #[must_use]
+    pub fn rust_name(&self) -> ::alloc::string::String {
+        match self.rust_name {
+            Some(rust_name) => rust_name.into(),
+            None => ::alloc::format!(
+                "from_{}",
+                to_snake_case(&::alloc::format!("{}", self.from.emit_ident()))
+            ),
+        }
+    }
+
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let from = self.from.emit_expr();
+        let rust_name = crate::cxx_auto_method::emit_option_str(self.rust_name);
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoConversion {
+                from: #from,
+                rust_name: #rust_name,
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        self.from.check()?;
+        crate::cxx_auto_method::check_ident("conversion", &self.rust_name())
+    }
+
+    pub(crate) fn emit_ident(&self) -> syn::Ident {
+        syn::Ident::new(&self.rust_name(), proc_macro2::Span::call_site())
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn to_snake_case(name: &str) -> ::alloc::string::String {
+    let chars = name.chars().collect::<::alloc::vec::Vec<_>>();
+    let mut snake = ::alloc::string::String::new();
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next = chars.get(index + 1).copied();
+            // NOTE: acronyms stay together, e.g., `HTTPServer` becomes `http_server`
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next.is_some_and(char::is_lowercase))
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(ch.to_lowercase());
+    }
+    snake
+}
@@ -1,8 +1,21 @@
+use proc_macro2::Span;
 use serde::Deserialize;
 
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CxxAutoEntryKind {
+    #[default]
+    Class,
+    Enum,
+}
+
 #[cfg(feature = "alloc")]
 #[derive(Deserialize)]
 pub struct CxxAutoEntry<'ctx> {
+    #[serde(default)]
+    kind: CxxAutoEntryKind,
     cxx_include: &'ctx str,
     cxx_proxy_include: Option<&'ctx str>,
     cxx_namespace: &'ctx str,
@@ -11,6 +24,29 @@ pub struct CxxAutoEntry<'ctx> {
     rust_name: &'ctx str,
     #[serde(default)]
     rust_lifetimes: ::indexmap::IndexMap<&'ctx str, ::alloc::vec::Vec<&'ctx str>>,
+    rust_default_new_visibility: Option<&'ctx str>,
+    #[serde(default)]
+    smart_pointers: bool,
+    #[serde(default)]
+    overrides: crate::CxxAutoOverrides,
+    #[serde(default)]
+    enumerators: ::alloc::vec::Vec<&'ctx str>,
+    #[serde(default)]
+    methods: ::alloc::vec::Vec<crate::CxxAutoMethod<'ctx>>,
+    #[serde(default)]
+    constructors: ::alloc::vec::Vec<crate::CxxAutoConstructor<'ctx>>,
+    #[serde(default)]
+    fields: ::alloc::vec::Vec<crate::CxxAutoField<'ctx>>,
+    range_item: Option<crate::CxxAutoTypeRef<'ctx>>,
+    index: Option<crate::CxxAutoIndex<'ctx>>,
+    debug_fmt: Option<crate::CxxAutoFmtSource<'ctx>>,
+    display_fmt: Option<crate::CxxAutoFmtSource<'ctx>>,
+    #[serde(default)]
+    conversions: ::alloc::vec::Vec<crate::CxxAutoConversion<'ctx>>,
+    #[serde(default)]
+    comparisons: ::alloc::vec::Vec<crate::CxxAutoTypeRef<'ctx>>,
+    #[serde(default)]
+    samples: ::alloc::vec::Vec<&'ctx str>,
 }
 
 #[cfg(feature = "alloc")]
@@ -20,15 +56,106 @@ impl<'ctx> CxxAutoEntry<'ctx> {
         self.cxx_name.unwrap_or(self.rust_name)
     }
 
+    #[must_use]
+    pub fn rust_default_new_visibility(&self) -> &str {
+        self.rust_default_new_visibility.unwrap_or("pub")
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        let visibility = self.rust_default_new_visibility();
+        syn::parse_str::<syn::Visibility>(visibility)
+            .map_err(|err| ::alloc::format!("cxx-auto: invalid Rust visibility `{visibility}`: {err}"))?;
+        for method in &self.methods {
+            method.check()?;
+        }
+        for constructor in &self.constructors {
+            constructor.check()?;
+        }
+        for field in &self.fields {
+            field.check()?;
+        }
+        let mut froms = ::alloc::collections::BTreeSet::new();
+        for conversion in &self.conversions {
+            conversion.check()?;
+            // NOTE: a second conversion from the same type would emit conflicting `From` or `TryFrom` impls
+            let from = conversion.from.rust_path;
+            if !froms.insert(from) {
+                let rust_name = self.rust_name;
+                return Err(::alloc::format!(
+                    "cxx-auto: invalid conversions for `{rust_name}`: more than one conversion from `{from}`"
+                )
+                .into());
+            }
+        }
+        let mut rhss = ::alloc::collections::BTreeSet::new();
+        for comparison in &self.comparisons {
+            comparison.check()?;
+            let rust_name = self.rust_name;
+            let rhs = comparison.rust_path;
+            // NOTE: the type is compared with itself through `PartialEq` and `PartialOrd` instead
+            let is_self_cxx =
+                comparison.cxx_namespace == Some(self.cxx_namespace) && comparison.cxx_name == Some(self.cxx_name());
+            if rhs == "Self" || is_self_cxx {
+                return Err(::alloc::format!(
+                    "cxx-auto: invalid comparisons for `{rust_name}`: the type cannot be compared with itself through \
+                     `comparisons`"
+                )
+                .into());
+            }
+            if !rhss.insert(rhs) {
+                return Err(::alloc::format!(
+                    "cxx-auto: invalid comparisons for `{rust_name}`: more than one comparison with `{rhs}`"
+                )
+                .into());
+            }
+        }
+        for sample in &self.samples {
+            syn::parse_str::<syn::Expr>(sample)
+                .map_err(|err| ::alloc::format!("cxx-auto: invalid Rust sample expression `{sample}`: {err}"))?;
+        }
+        if self.kind == CxxAutoEntryKind::Enum && !self.samples.is_empty() {
+            let rust_name = self.rust_name;
+            return Err(::alloc::format!(
+                "cxx-auto: invalid samples for enum `{rust_name}`: laws are only checked for classes"
+            )
+            .into());
+        }
+        if let Some(range_item) = &self.range_item {
+            range_item.check()?;
+        }
+        if let Some(index) = &self.index {
+            index.check()?;
+        }
+        if let Some(debug_fmt) = &self.debug_fmt {
+            debug_fmt.check()?;
+        }
+        if let Some(display_fmt) = &self.display_fmt {
+            display_fmt.check()?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
     pub(crate) fn emit_items_write_module_for_file<'a, 'b>(
         &self,
         path_components: impl Iterator<Item = &'a ::alloc::string::String>,
         path_descendants: impl Iterator<Item = &'b ::alloc::string::String>,
     ) -> ::alloc::vec::Vec<syn::ItemFn> {
+        let item_fn_write_module: syn::ItemFn = syn::parse_quote! {
+            pub(crate) fn write_module(auto_out_dir_root: &::std::path::Path) -> ::cxx_auto::BoxResult<()> {
+                self::artifact_info()?.write_module_for_file(auto_out_dir_root)
+            }
+        };
+        if self.kind == CxxAutoEntryKind::Enum {
+            let item_fn_artifact_info = self.emit_item_fn_enum_artifact_info(path_components, path_descendants);
+            return ::alloc::vec![item_fn_artifact_info, item_fn_write_module];
+        }
         let cxx_include = self.cxx_include;
         let cxx_namespace = self.cxx_namespace;
         let cxx_name = self.cxx_name();
         let rust_name = self.rust_name;
+        let rust_default_new_visibility = self.rust_default_new_visibility();
+        let smart_pointers = self.smart_pointers;
         let lifetimes = {
             let mut exprs = ::alloc::vec::Vec::<syn::Expr>::new();
             for (lifetime, bounds) in &self.rust_lifetimes {
@@ -36,9 +163,108 @@ impl<'ctx> CxxAutoEntry<'ctx> {
             }
             exprs
         };
+        let overrides = self.overrides.emit_expr();
+        let methods = self.methods.iter().map(crate::CxxAutoMethod::emit_expr);
+        let constructors = self.constructors.iter().map(crate::CxxAutoConstructor::emit_expr);
+        let range_item: syn::Expr = match &self.range_item {
+            Some(range_item) => {
+                let range_item = range_item.emit_expr();
+                syn::parse_quote!(Some(#range_item))
+            },
+            None => syn::parse_quote!(None),
+        };
+        let index: syn::Expr = match &self.index {
+            Some(index) => {
+                let index = index.emit_expr();
+                syn::parse_quote!(Some(#index))
+            },
+            None => syn::parse_quote!(None),
+        };
+        let emit_fmt = |fmt: Option<&crate::CxxAutoFmtSource>| -> syn::Expr {
+            match fmt {
+                Some(fmt) => {
+                    let fmt = fmt.emit_expr();
+                    syn::parse_quote!(Some(#fmt))
+                },
+                None => syn::parse_quote!(None),
+            }
+        };
+        let debug_fmt = emit_fmt(self.debug_fmt.as_ref());
+        let display_fmt = emit_fmt(self.display_fmt.as_ref());
+        // NOTE: an explicitly chosen formatting source decides by itself whether the trait is implemented by default
+        let is_rust_debug: syn::Expr = match &self.debug_fmt {
+            Some(fmt) => {
+                let probe = fmt.emit_probe();
+                syn::parse_quote!(overrides.debug.unwrap_or(#probe))
+            },
+            None => syn::parse_quote!(overrides.debug.unwrap_or_else(self::ffi::rust_should_impl_debug)),
+        };
+        let is_rust_display: syn::Expr = match &self.display_fmt {
+            Some(fmt) => {
+                let probe = fmt.emit_probe();
+                syn::parse_quote!(overrides.display.unwrap_or(#probe))
+            },
+            None => syn::parse_quote!(overrides.display.unwrap_or_else(self::ffi::rust_should_impl_display)),
+        };
+        // NOTE: the index type of the `operator[]` probes is deduced in C++ from the type of the unused argument
+        let subscript: syn::Expr = if self.index.is_some() {
+            syn::parse_quote! {
+                (
+                    self::ffi::cxx_has_operator_subscript_const(::core::default::Default::default()),
+                    self::ffi::cxx_has_operator_subscript(::core::default::Default::default()),
+                )
+            }
+        } else {
+            syn::parse_quote!((false, false))
+        };
+        let fields = self.fields.iter().enumerate().map(|(index, field)| -> syn::Expr {
+            let field = field.emit_expr();
+            let offset = syn::Ident::new(&::alloc::format!("cxx_field_offset_{index}"), Span::call_site());
+            let size = syn::Ident::new(&::alloc::format!("cxx_field_size_{index}"), Span::call_site());
+            syn::parse_quote!((#field, self::ffi::#offset(), self::ffi::#size()))
+        });
+        // NOTE: the source type of the conversion probes is deduced in C++ from the type of the unused pointer
+        let conversions = self.conversions.iter().enumerate().map(|(index, conversion)| -> syn::Expr {
+            let conversion = conversion.emit_expr();
+            let is_convertible =
+                syn::Ident::new(&::alloc::format!("cxx_is_convertible_from_{index}"), Span::call_site());
+            let is_nothrow_convertible =
+                syn::Ident::new(&::alloc::format!("cxx_is_nothrow_convertible_from_{index}"), Span::call_site());
+            syn::parse_quote! {
+                (
+                    #conversion,
+                    unsafe { self::ffi::#is_convertible(::core::ptr::null()) },
+                    unsafe { self::ffi::#is_nothrow_convertible(::core::ptr::null()) },
+                )
+            }
+        });
+        // NOTE: `str` has no raw pointer in the bridge, so its probes take an empty string instead
+        let comparisons = self.comparisons.iter().enumerate().map(|(index, comparison)| -> syn::Expr {
+            let rhs = comparison.emit_expr();
+            let has_operator_equal =
+                syn::Ident::new(&::alloc::format!("cxx_has_operator_equal_with_{index}"), Span::call_site());
+            let is_partially_ordered =
+                syn::Ident::new(&::alloc::format!("cxx_is_partially_ordered_with_{index}"), Span::call_site());
+            if comparison.is_str() {
+                syn::parse_quote! {
+                    (#rhs, self::ffi::#has_operator_equal(""), self::ffi::#is_partially_ordered(""))
+                }
+            } else {
+                syn::parse_quote! {
+                    (
+                        #rhs,
+                        unsafe { self::ffi::#has_operator_equal(::core::ptr::null()) },
+                        unsafe { self::ffi::#is_partially_ordered(::core::ptr::null()) },
+                    )
+                }
+            }
+        });
+        let samples = &self.samples;
+        let cxx_operators = crate::cxx_auto_artifact_info::operators().map(|(_, cxx_operator, ..)| cxx_operator);
+        let cxx_has_operators = emit_cxx_has_operator_idents();
         ::alloc::vec![
             syn::parse_quote! {
-                fn artifact_info() -> ::cxx_auto::CxxAutoArtifactInfo {
+                fn artifact_info() -> ::cxx_auto::BoxResult<::cxx_auto::CxxAutoArtifactInfo> {
                     let path_components = vec![#(#path_components),*];
                     let path_descendants = vec![#(#path_descendants),*];
                     let cxx_include = #cxx_include;
@@ -46,6 +272,18 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                     let cxx_name = #cxx_name;
                     let rust_name = #rust_name;
                     let lifetimes = ::cxx_auto::indexmap::IndexMap::from_iter([#(#lifetimes),*]);
+                    let rust_default_new_visibility = #rust_default_new_visibility;
+                    let smart_pointers = #smart_pointers;
+                    let methods = vec![#(#methods),*];
+                    let constructors = vec![#(#constructors),*];
+                    let fields = vec![#(#fields),*];
+                    let conversions = vec![#(#conversions),*];
+                    let comparisons = vec![#(#comparisons),*];
+                    let samples = vec![#(#samples),*];
+                    let range_item = #range_item;
+                    let index = #index;
+                    let debug_fmt = #debug_fmt;
+                    let display_fmt = #display_fmt;
                     let align = self::ffi::cxx_abi_align();
                     let size = self::ffi::cxx_abi_size();
                     let cxx_has_operator_equal = self::ffi::cxx_has_operator_equal();
@@ -54,8 +292,35 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                     let cxx_has_operator_less_than_or_equal = self::ffi::cxx_has_operator_less_than_or_equal();
                     let cxx_has_operator_greater_than = self::ffi::cxx_has_operator_greater_than();
                     let cxx_has_operator_greater_than_or_equal = self::ffi::cxx_has_operator_greater_than_or_equal();
+                    let cxx_operators = [#((#cxx_operators, self::ffi::#cxx_has_operators())),*]
+                        .into_iter()
+                        .filter_map(|(cxx_operator, is_detected)| is_detected.then_some(cxx_operator))
+                        .collect();
+                    let (cxx_has_operator_subscript_const, cxx_has_operator_subscript) = #subscript;
+                    let cxx_has_size = self::ffi::cxx_has_size();
+                    let cxx_is_default_constructible = self::ffi::cxx_is_default_constructible();
+                    let cxx_is_copy_constructible = self::ffi::cxx_is_copy_constructible();
+                    let cxx_is_move_constructible = self::ffi::cxx_is_move_constructible();
+                    let cxx_is_copy_assignable = self::ffi::cxx_is_copy_assignable();
+                    let cxx_is_move_assignable = self::ffi::cxx_is_move_assignable();
+                    let cxx_is_destructible = self::ffi::cxx_is_destructible();
+                    let cxx_is_trivially_copyable = self::ffi::cxx_is_trivially_copyable();
+                    let cxx_is_trivially_movable = self::ffi::cxx_is_trivially_movable();
+                    let cxx_is_trivially_destructible = self::ffi::cxx_is_trivially_destructible();
+                    let cxx_is_partially_ordered = self::ffi::cxx_is_partially_ordered();
+                    let cxx_is_weakly_ordered = self::ffi::cxx_is_weakly_ordered();
+                    let cxx_is_strongly_ordered = self::ffi::cxx_is_strongly_ordered();
+                    let cxx_is_hashable = self::ffi::cxx_is_hashable();
+                    let cxx_is_debuggable = self::ffi::cxx_is_debuggable();
+                    let cxx_is_displayable = self::ffi::cxx_is_displayable();
+                    let cxx_is_string_convertible = self::ffi::cxx_is_string_convertible();
+                    let cxx_has_std_formatter = self::ffi::cxx_has_std_formatter();
+                    let cxx_is_standard_layout = self::ffi::cxx_is_standard_layout();
+                    let cxx_is_iterable = self::ffi::cxx_is_iterable();
+                    let cxx_is_constructible_from_iterator = self::ffi::cxx_is_constructible_from_iterator();
+                    let cxx_is_parsable = self::ffi::cxx_is_parsable();
+                    let overrides = #overrides;
                     let is_rust_cxx_extern_type_trivial = {
-                        let cxx_is_trivially_movable = self::ffi::cxx_is_trivially_movable();
                         let rust_should_impl_cxx_extern_type_trivial = self::ffi::rust_should_impl_cxx_extern_type_trivial();
                         if cxx_is_trivially_movable == rust_should_impl_cxx_extern_type_trivial {
                             cxx_is_trivially_movable
@@ -63,22 +328,24 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                             rust_should_impl_cxx_extern_type_trivial
                         }
                     };
-                    let is_rust_unpin = self::ffi::rust_should_impl_unpin();
-                    let is_rust_send = self::ffi::rust_should_impl_send();
-                    let is_rust_sync = self::ffi::rust_should_impl_sync();
-                    let is_rust_copy = self::ffi::rust_should_impl_copy();
-                    let is_rust_drop = self::ffi::rust_should_impl_drop();
-                    let is_rust_debug = self::ffi::rust_should_impl_debug();
-                    let is_rust_default = self::ffi::rust_should_impl_default();
-                    let is_rust_display = self::ffi::rust_should_impl_display();
-                    let is_rust_copy_new = self::ffi::rust_should_impl_moveref_copy_new();
-                    let is_rust_move_new = self::ffi::rust_should_impl_moveref_move_new();
-                    let is_rust_eq = self::ffi::rust_should_impl_eq();
-                    let is_rust_partial_eq = self::ffi::rust_should_impl_partial_eq();
-                    let is_rust_partial_ord = self::ffi::rust_should_impl_partial_ord();
-                    let is_rust_ord = self::ffi::rust_should_impl_ord();
-                    let is_rust_hash = self::ffi::rust_should_impl_hash();
-                    ::cxx_auto::CxxAutoArtifactInfo {
+                    let is_rust_unpin = overrides.unpin.unwrap_or_else(self::ffi::rust_should_impl_unpin);
+                    let is_rust_send = overrides.send.unwrap_or_else(self::ffi::rust_should_impl_send);
+                    let is_rust_sync = overrides.sync.unwrap_or_else(self::ffi::rust_should_impl_sync);
+                    let is_rust_copy = overrides.copy.unwrap_or_else(self::ffi::rust_should_impl_copy);
+                    let is_rust_drop = overrides.drop.unwrap_or_else(self::ffi::rust_should_impl_drop);
+                    let is_rust_debug = #is_rust_debug;
+                    let is_rust_default = overrides.default.unwrap_or_else(self::ffi::rust_should_impl_default);
+                    let is_rust_display = #is_rust_display;
+                    let is_rust_copy_new = overrides.copy_new.unwrap_or_else(self::ffi::rust_should_impl_moveref_copy_new);
+                    let is_rust_move_new = overrides.move_new.unwrap_or_else(self::ffi::rust_should_impl_moveref_move_new);
+                    let is_rust_eq = overrides.eq.unwrap_or_else(self::ffi::rust_should_impl_eq);
+                    let is_rust_partial_eq = overrides.partial_eq.unwrap_or_else(self::ffi::rust_should_impl_partial_eq);
+                    let is_rust_partial_ord = overrides.partial_ord.unwrap_or_else(self::ffi::rust_should_impl_partial_ord);
+                    let is_rust_ord = overrides.ord.unwrap_or_else(self::ffi::rust_should_impl_ord);
+                    let is_rust_hash = overrides.hash.unwrap_or_else(self::ffi::rust_should_impl_hash);
+                    let is_rust_from_iterator = overrides.from_iterator.unwrap_or_else(self::ffi::rust_should_impl_from_iterator);
+                    let is_rust_from_str = overrides.from_str.unwrap_or_else(self::ffi::rust_should_impl_from_str);
+                    Ok(::cxx_auto::CxxAutoArtifactInfo {
                         path_components,
                         path_descendants,
                         cxx_include,
@@ -86,6 +353,20 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         cxx_name,
                         rust_name,
                         lifetimes,
+                        rust_default_new_visibility,
+                        smart_pointers,
+                        enum_repr: None,
+                        enumerators: vec![],
+                        methods,
+                        constructors,
+                        fields,
+                        conversions,
+                        comparisons,
+                        samples,
+                        range_item,
+                        index,
+                        debug_fmt,
+                        display_fmt,
                         align,
                         size,
                         cxx_has_operator_equal,
@@ -94,6 +375,32 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         cxx_has_operator_less_than_or_equal,
                         cxx_has_operator_greater_than,
                         cxx_has_operator_greater_than_or_equal,
+                        cxx_operators,
+                        cxx_has_operator_subscript_const,
+                        cxx_has_operator_subscript,
+                        cxx_has_size,
+                        cxx_is_default_constructible,
+                        cxx_is_copy_constructible,
+                        cxx_is_move_constructible,
+                        cxx_is_copy_assignable,
+                        cxx_is_move_assignable,
+                        cxx_is_destructible,
+                        cxx_is_trivially_copyable,
+                        cxx_is_trivially_movable,
+                        cxx_is_trivially_destructible,
+                        cxx_is_partially_ordered,
+                        cxx_is_weakly_ordered,
+                        cxx_is_strongly_ordered,
+                        cxx_is_hashable,
+                        cxx_is_debuggable,
+                        cxx_is_displayable,
+                        cxx_is_string_convertible,
+                        cxx_has_std_formatter,
+                        cxx_is_standard_layout,
+                        cxx_is_iterable,
+                        cxx_is_constructible_from_iterator,
+                        cxx_is_parsable,
+                        overrides,
                         is_rust_cxx_extern_type_trivial,
                         is_rust_unpin,
                         is_rust_send,
@@ -110,23 +417,212 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         is_rust_partial_ord,
                         is_rust_ord,
                         is_rust_hash,
-                    }
-                }
-            },
-            syn::parse_quote! {
-                pub(crate) fn write_module(auto_out_dir_root: &::std::path::Path) -> ::cxx_auto::BoxResult<()> {
-                    self::artifact_info().write_module_for_file(auto_out_dir_root)
+                        is_rust_from_iterator,
+                        is_rust_from_str,
+                    })
                 }
             },
+            item_fn_write_module,
         ]
     }
 
+    // NOTE: enums are bound as plain `repr` enums, so only their layout and enumerators are probed
+    fn emit_item_fn_enum_artifact_info<'a, 'b>(
+        &self,
+        path_components: impl Iterator<Item = &'a ::alloc::string::String>,
+        path_descendants: impl Iterator<Item = &'b ::alloc::string::String>,
+    ) -> syn::ItemFn {
+        let cxx_include = self.cxx_include;
+        let cxx_namespace = self.cxx_namespace;
+        let cxx_name = self.cxx_name();
+        let rust_name = self.rust_name;
+        let enumerators = self.enumerators.iter().enumerate().map(|(index, name)| -> syn::Expr {
+            let ident = syn::Ident::new(&::alloc::format!("cxx_enumerator_{index}"), Span::call_site());
+            syn::parse_quote!((#name, self::ffi::#ident()))
+        });
+        syn::parse_quote! {
+            fn artifact_info() -> ::cxx_auto::BoxResult<::cxx_auto::CxxAutoArtifactInfo> {
+                let path_components = vec![#(#path_components),*];
+                let path_descendants = vec![#(#path_descendants),*];
+                let cxx_include = #cxx_include;
+                let cxx_namespace = #cxx_namespace;
+                let cxx_name = #cxx_name;
+                let rust_name = #rust_name;
+                let align = self::ffi::cxx_abi_align();
+                let size = self::ffi::cxx_abi_size();
+                let is_signed = self::ffi::cxx_enum_is_signed();
+                let enum_repr = ::cxx_auto::CxxAutoEnumRepr::new(cxx_namespace, cxx_name, size, is_signed)?;
+                let enumerators = vec![#(#enumerators),*];
+                Ok(::cxx_auto::CxxAutoArtifactInfo {
+                    path_components,
+                    path_descendants,
+                    cxx_include,
+                    cxx_namespace,
+                    cxx_name,
+                    rust_name,
+                    enum_repr: Some(enum_repr),
+                    enumerators,
+                    align,
+                    size,
+                    is_rust_cxx_extern_type_trivial: true,
+                    ..::core::default::Default::default()
+                })
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
     pub(crate) fn emit_item_mod_cxx_bridge(&self) -> [syn::Item; 2] {
         let namespace: syn::Attribute = {
             let namespace = self.cxx_proxy_namespace.unwrap_or(self.cxx_namespace);
             syn::parse_quote!(#[namespace = #namespace])
         };
         let include = self.cxx_proxy_include.unwrap_or(self.cxx_include);
+        let cxx_enumerators = self.enumerators.iter().enumerate().map(|(index, name)| -> syn::ForeignItemFn {
+            let ident = syn::Ident::new(&::alloc::format!("cxx_enumerator_{index}"), Span::call_site());
+            let cxx_name = ::alloc::format!("cxx_enumerator_{name}");
+            syn::parse_quote! {
+                #[cxx_name = #cxx_name]
+                #[must_use]
+                fn #ident() -> u64;
+            }
+        });
+        if self.kind == CxxAutoEntryKind::Enum {
+            return [
+                syn::parse_quote! {
+                    #[cxx::bridge]
+                    mod ffi {
+                        #namespace
+                        unsafe extern "C++" {
+                            include!(#include);
+                            #[must_use]
+                            fn cxx_abi_align() -> usize;
+                            #[must_use]
+                            fn cxx_abi_size() -> usize;
+                            #[must_use]
+                            fn cxx_enum_is_signed() -> bool;
+                            #(#cxx_enumerators)*
+                        }
+                    }
+                },
+                syn::parse_quote! {
+                    pub use ffi::*;
+                },
+            ];
+        }
+        let cxx_fields = self.fields.iter().enumerate().flat_map(|(index, field)| -> [syn::ForeignItemFn; 2] {
+            let offset = syn::Ident::new(&::alloc::format!("cxx_field_offset_{index}"), Span::call_site());
+            let offset_cxx_name = ::alloc::format!("cxx_field_offset_{}", field.name);
+            let size = syn::Ident::new(&::alloc::format!("cxx_field_size_{index}"), Span::call_site());
+            let size_cxx_name = ::alloc::format!("cxx_field_size_{}", field.name);
+            [
+                syn::parse_quote! {
+                    #[cxx_name = #offset_cxx_name]
+                    #[must_use]
+                    fn #offset() -> usize;
+                },
+                syn::parse_quote! {
+                    #[cxx_name = #size_cxx_name]
+                    #[must_use]
+                    fn #size() -> usize;
+                },
+            ]
+        });
+        let cxx_conversions = self.conversions.iter().enumerate().flat_map(|(index, conversion)| {
+            let from = &conversion.from;
+            let (ty, item_type) = match (from.cxx_namespace, from.cxx_name) {
+                (Some(cxx_namespace), Some(cxx_name)) => {
+                    let ty = syn::Ident::new(&::alloc::format!("CxxConversionFrom{index}"), Span::call_site());
+                    let item_type: syn::ForeignItem = syn::parse_quote! {
+                        #[namespace = #cxx_namespace]
+                        #[cxx_name = #cxx_name]
+                        type #ty;
+                    };
+                    (ty, Some(item_type))
+                },
+                _ => (from.emit_ident(), None),
+            };
+            let is_convertible =
+                syn::Ident::new(&::alloc::format!("cxx_is_convertible_from_{index}"), Span::call_site());
+            let is_nothrow_convertible =
+                syn::Ident::new(&::alloc::format!("cxx_is_nothrow_convertible_from_{index}"), Span::call_site());
+            let items: [syn::ForeignItem; 2] = [
+                syn::parse_quote! {
+                    #[cxx_name = "cxx_is_convertible_from"]
+                    #[must_use]
+                    unsafe fn #is_convertible(that: *const #ty) -> bool;
+                },
+                syn::parse_quote! {
+                    #[cxx_name = "cxx_is_nothrow_convertible_from"]
+                    #[must_use]
+                    unsafe fn #is_nothrow_convertible(that: *const #ty) -> bool;
+                },
+            ];
+            item_type.into_iter().chain(items)
+        });
+        let cxx_comparisons = self.comparisons.iter().enumerate().flat_map(|(index, comparison)| {
+            let has_operator_equal =
+                syn::Ident::new(&::alloc::format!("cxx_has_operator_equal_with_{index}"), Span::call_site());
+            let is_partially_ordered =
+                syn::Ident::new(&::alloc::format!("cxx_is_partially_ordered_with_{index}"), Span::call_site());
+            if comparison.is_str() {
+                let items: [syn::ForeignItem; 2] = [
+                    syn::parse_quote! {
+                        #[cxx_name = "cxx_has_operator_equal_with"]
+                        #[must_use]
+                        fn #has_operator_equal(that: &str) -> bool;
+                    },
+                    syn::parse_quote! {
+                        #[cxx_name = "cxx_is_partially_ordered_with"]
+                        #[must_use]
+                        fn #is_partially_ordered(that: &str) -> bool;
+                    },
+                ];
+                return None.into_iter().chain(items);
+            }
+            let (ty, item_type) = match (comparison.cxx_namespace, comparison.cxx_name) {
+                (Some(cxx_namespace), Some(cxx_name)) => {
+                    let ty = syn::Ident::new(&::alloc::format!("CxxComparisonWith{index}"), Span::call_site());
+                    let item_type: syn::ForeignItem = syn::parse_quote! {
+                        #[namespace = #cxx_namespace]
+                        #[cxx_name = #cxx_name]
+                        type #ty;
+                    };
+                    (ty, Some(item_type))
+                },
+                _ => (comparison.emit_ident(), None),
+            };
+            let items: [syn::ForeignItem; 2] = [
+                syn::parse_quote! {
+                    #[cxx_name = "cxx_has_operator_equal_with"]
+                    #[must_use]
+                    unsafe fn #has_operator_equal(that: *const #ty) -> bool;
+                },
+                syn::parse_quote! {
+                    #[cxx_name = "cxx_is_partially_ordered_with"]
+                    #[must_use]
+                    unsafe fn #is_partially_ordered(that: *const #ty) -> bool;
+                },
+            ];
+            item_type.into_iter().chain(items)
+        });
+        let cxx_has_operators = emit_cxx_has_operator_idents();
+        let cxx_subscripts: ::alloc::vec::Vec<syn::ForeignItemFn> = match &self.index {
+            Some(index) => {
+                let ty = index.emit_type();
+                ::alloc::vec![
+                    syn::parse_quote! {
+                        #[must_use]
+                        fn cxx_has_operator_subscript_const(index: #ty) -> bool;
+                    },
+                    syn::parse_quote! {
+                        #[must_use]
+                        fn cxx_has_operator_subscript(index: #ty) -> bool;
+                    },
+                ]
+            },
+            None => ::alloc::vec![],
+        };
         [
             syn::parse_quote! {
                 #[cxx::bridge]
@@ -145,6 +641,10 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         #[must_use]
                         fn cxx_is_default_constructible() -> bool;
                         #[must_use]
+                        fn cxx_is_copy_assignable() -> bool;
+                        #[must_use]
+                        fn cxx_is_move_assignable() -> bool;
+                        #[must_use]
                         fn cxx_is_destructible() -> bool;
                         #[must_use]
                         fn cxx_is_trivially_copyable() -> bool;
@@ -166,13 +666,43 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         fn cxx_has_operator_greater_than() -> bool;
                         #[must_use]
                         fn cxx_has_operator_greater_than_or_equal() -> bool;
+                        #(
+                            #[must_use]
+                            fn #cxx_has_operators() -> bool;
+                        )*
+                        #(#cxx_subscripts)*
+                        #[must_use]
+                        fn cxx_has_size() -> bool;
                         #[must_use]
                         fn cxx_is_partially_ordered() -> bool;
                         #[must_use]
+                        fn cxx_is_weakly_ordered() -> bool;
+                        #[must_use]
+                        fn cxx_is_strongly_ordered() -> bool;
+                        #[must_use]
                         fn cxx_is_totally_ordered() -> bool;
                         #[must_use]
                         fn cxx_is_hashable() -> bool;
                         #[must_use]
+                        fn cxx_is_debuggable() -> bool;
+                        #[must_use]
+                        fn cxx_is_displayable() -> bool;
+                        #[must_use]
+                        fn cxx_has_std_formatter() -> bool;
+                        #[must_use]
+                        fn cxx_is_string_convertible() -> bool;
+                        #[must_use]
+                        fn cxx_is_standard_layout() -> bool;
+                        #[must_use]
+                        fn cxx_is_iterable() -> bool;
+                        #[must_use]
+                        fn cxx_is_constructible_from_iterator() -> bool;
+                        #[must_use]
+                        fn cxx_is_parsable() -> bool;
+                        #(#cxx_fields)*
+                        #(#cxx_conversions)*
+                        #(#cxx_comparisons)*
+                        #[must_use]
                         fn rust_should_impl_cxx_extern_type_trivial() -> bool;
                         #[must_use]
                         fn rust_should_impl_unpin() -> bool;
@@ -204,6 +734,10 @@ impl<'ctx> CxxAutoEntry<'ctx> {
                         fn rust_should_impl_ord() -> bool;
                         #[must_use]
                         fn rust_should_impl_hash() -> bool;
+                        #[must_use]
+                        fn rust_should_impl_from_iterator() -> bool;
+                        #[must_use]
+                        fn rust_should_impl_from_str() -> bool;
                     }
                 }
             },
@@ -213,3 +747,10 @@ impl<'ctx> CxxAutoEntry<'ctx> {
         ]
     }
 }
+
+#[cfg(feature = "alloc")]
+fn emit_cxx_has_operator_idents() -> impl Iterator<Item = syn::Ident> {
+    crate::cxx_auto_artifact_info::operators().map(|(_, cxx_operator, ..)| {
+        syn::Ident::new(&::alloc::format!("cxx_has_operator_{cxx_operator}"), Span::call_site())
+    })
+}
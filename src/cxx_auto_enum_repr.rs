@@ -0,0 +1,57 @@
+// NOTE: the Rust integer type matching the underlying type of a C++ enum
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CxxAutoEnumRepr {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoEnumRepr {
+    /// # Errors
+    ///
+    /// Will return `Err` if the underlying type of the enum `cxx_namespace::cxx_name` is not 1, 2, 4, or 8 bytes wide.
+    pub fn new(cxx_namespace: &str, cxx_name: &str, size: usize, is_signed: bool) -> crate::BoxResult<Self> {
+        match (size, is_signed) {
+            (1, true) => Ok(Self::I8),
+            (2, true) => Ok(Self::I16),
+            (4, true) => Ok(Self::I32),
+            (8, true) => Ok(Self::I64),
+            (1, false) => Ok(Self::U8),
+            (2, false) => Ok(Self::U16),
+            (4, false) => Ok(Self::U32),
+            (8, false) => Ok(Self::U64),
+            _ => Err(::alloc::format!(
+                "cxx-auto: cannot bind enum `{cxx_namespace}::{cxx_name}`: the underlying type is {size} bytes wide, \
+                 but only 1, 2, 4, or 8 bytes are supported"
+            )
+            .into()),
+        }
+    }
+
+    #[must_use]
+    pub fn is_signed(self) -> bool {
+        matches!(self, Self::I8 | Self::I16 | Self::I32 | Self::I64)
+    }
+
+    pub(crate) fn emit_ident(self) -> syn::Ident {
+        let repr = match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+        };
+        syn::Ident::new(repr, proc_macro2::Span::call_site())
+    }
+}
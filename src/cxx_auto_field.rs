@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoField<'ctx> {
+    pub name: &'ctx str,
+    pub rust_name: Option<&'ctx str>,
+    #[serde(rename = "type")]
+    pub ty: &'ctx str,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoField<'_> {
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        self.rust_name.unwrap_or(self.name)
+    }
+
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let name = self.name;
+        let rust_name = crate::cxx_auto_method::emit_option_str(self.rust_name);
+        let ty = self.ty;
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoField {
+                name: #name,
+                rust_name: #rust_name,
+                ty: #ty,
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        crate::cxx_auto_method::check_ident("field", self.name)?;
+        crate::cxx_auto_method::check_ident("field", self.rust_name())?;
+        crate::cxx_auto_method::check_type(self.ty)
+    }
+
+    pub(crate) fn emit_ident(&self) -> syn::Ident {
+        syn::Ident::new(self.rust_name(), proc_macro2::Span::call_site())
+    }
+
+    pub(crate) fn emit_type(&self) -> syn::Type {
+        crate::cxx_auto_method::emit_type(self.ty)
+    }
+}
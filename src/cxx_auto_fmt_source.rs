@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+// NOTE: the C++ facility backing the Rust `Debug` or `Display` impl of an entry
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CxxAutoFmtSource<'ctx> {
+    /// `operator<<` on a `std::ostream`
+    Ostream,
+    /// A `std::formatter` specialization
+    Formatter,
+    /// `std::to_string`, or a conversion to `std::string` or `std::string_view`
+    ToString,
+    /// A `const` member function whose result is a string or supports `operator<<`
+    Member(&'ctx str),
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoFmtSource<'_> {
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        match self {
+            Self::Ostream => syn::parse_quote!(::cxx_auto::CxxAutoFmtSource::Ostream),
+            Self::Formatter => syn::parse_quote!(::cxx_auto::CxxAutoFmtSource::Formatter),
+            Self::ToString => syn::parse_quote!(::cxx_auto::CxxAutoFmtSource::ToString),
+            Self::Member(name) => syn::parse_quote!(::cxx_auto::CxxAutoFmtSource::Member(#name)),
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        if let Self::Member(name) = self {
+            syn::parse_str::<syn::Ident>(name)
+                .map_err(|err| ::alloc::format!("cxx-auto: invalid C++ member name `{name}`: {err}"))?;
+        }
+        Ok(())
+    }
+
+    // NOTE: members are bound through `CXX_AUTO_FMT_MEMBER`, so a missing member fails to compile instead
+    pub(crate) fn emit_probe(&self) -> syn::Expr {
+        match self {
+            Self::Ostream => syn::parse_quote!(self::ffi::cxx_is_debuggable()),
+            Self::Formatter => syn::parse_quote!(self::ffi::cxx_has_std_formatter()),
+            Self::ToString => syn::parse_quote!(self::ffi::cxx_is_string_convertible()),
+            Self::Member(_) => syn::parse_quote!(true),
+        }
+    }
+
+    pub(crate) fn is_supported(&self, info: &crate::CxxAutoArtifactInfo) -> (bool, &'static str) {
+        match self {
+            Self::Ostream => (info.cxx_is_debuggable, "has no `operator<<`"),
+            Self::Formatter => (info.cxx_has_std_formatter, "has no `std::formatter` specialization"),
+            Self::ToString => (info.cxx_is_string_convertible, "is not convertible to a string"),
+            Self::Member(_) => (true, ""),
+        }
+    }
+
+    pub(crate) fn emit_cxx_ident(&self) -> syn::Ident {
+        let name = match self {
+            Self::Ostream => "cxx_debug".into(),
+            Self::Formatter => "cxx_display_format".into(),
+            Self::ToString => "cxx_display".into(),
+            Self::Member(name) => ::alloc::format!("cxx_fmt_member_{name}"),
+        };
+        syn::Ident::new(&name, proc_macro2::Span::call_site())
+    }
+}
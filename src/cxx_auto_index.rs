@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+// NOTE: the index type is passed through the `cxx` bridge by value, so it must be a builtin integer type
+const INDEX_TYPES: [&str; 10] = ["u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize"];
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoIndex<'ctx> {
+    #[serde(rename = "type")]
+    pub ty: &'ctx str,
+    pub output: crate::CxxAutoTypeRef<'ctx>,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoIndex<'_> {
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let ty = self.ty;
+        let output = self.output.emit_expr();
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoIndex {
+                ty: #ty,
+                output: #output,
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        let ty = self.ty;
+        if !INDEX_TYPES.contains(&ty) {
+            return Err(::alloc::format!("cxx-auto: index type `{ty}` must be a builtin integer type").into());
+        }
+        self.output.check()
+    }
+
+    pub(crate) fn emit_type(&self) -> syn::Type {
+        crate::cxx_auto_method::emit_type(self.ty)
+    }
+}
@@ -0,0 +1,186 @@
+use serde::Deserialize;
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoArg<'ctx> {
+    pub name: &'ctx str,
+    #[serde(rename = "type")]
+    pub ty: &'ctx str,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoMethod<'ctx> {
+    pub name: &'ctx str,
+    pub rust_name: Option<&'ctx str>,
+    #[serde(default)]
+    pub is_const: bool,
+    #[serde(default)]
+    pub args: ::alloc::vec::Vec<CxxAutoArg<'ctx>>,
+    pub returns: Option<&'ctx str>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoConstructor<'ctx> {
+    pub rust_name: &'ctx str,
+    #[serde(default)]
+    pub args: ::alloc::vec::Vec<CxxAutoArg<'ctx>>,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoArg<'_> {
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let name = self.name;
+        let ty = self.ty;
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoArg { name: #name, ty: #ty }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        check_ident("argument", self.name)?;
+        check_type(self.ty)
+    }
+
+    pub(crate) fn emit_fn_arg(&self) -> syn::FnArg {
+        let name = syn::Ident::new(self.name, proc_macro2::Span::call_site());
+        let ty = emit_type(self.ty);
+        syn::parse_quote!(#name: #ty)
+    }
+
+    pub(crate) fn emit_ident(&self) -> syn::Ident {
+        syn::Ident::new(self.name, proc_macro2::Span::call_site())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoMethod<'_> {
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        self.rust_name.unwrap_or(self.name)
+    }
+
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let name = self.name;
+        let rust_name = emit_option_str(self.rust_name);
+        let is_const = self.is_const;
+        let args = self.args.iter().map(CxxAutoArg::emit_expr);
+        let returns = emit_option_str(self.returns);
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoMethod {
+                name: #name,
+                rust_name: #rust_name,
+                is_const: #is_const,
+                args: vec![#(#args),*],
+                returns: #returns,
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        check_ident("method", self.rust_name())?;
+        for arg in &self.args {
+            arg.check()?;
+        }
+        if let Some(returns) = self.returns {
+            check_type(returns)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_return_type(&self) -> syn::ReturnType {
+        if let Some(returns) = self.returns {
+            let ty = emit_type(returns);
+            syn::parse_quote!(-> #ty)
+        } else {
+            syn::ReturnType::Default
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoConstructor<'_> {
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let rust_name = self.rust_name;
+        let args = self.args.iter().map(CxxAutoArg::emit_expr);
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoConstructor {
+                rust_name: #rust_name,
+                args: vec![#(#args),*],
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        check_ident("constructor", self.rust_name)?;
+        for arg in &self.args {
+            arg.check()?;
+            if has_nested_reference(&emit_type(arg.ty)) {
+                let (rust_name, name, ty) = (self.rust_name, arg.name, arg.ty);
+                return Err(::alloc::format!(
+                    "cxx-auto: invalid Rust type `{ty}` for argument `{name}` of constructor `{rust_name}`: only \
+                     top-level references are supported"
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    // NOTE: reference arguments must outlive the returned `New`, which is only run later; only a top-level reference
+    // can be tied to that lifetime, so nested references are rejected by `check`
+    pub(crate) fn has_reference_args(&self) -> bool {
+        self.args
+            .iter()
+            .any(|arg| matches!(emit_type(arg.ty), syn::Type::Reference(_)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn emit_option_str(value: Option<&str>) -> syn::Expr {
+    match value {
+        Some(value) => syn::parse_quote!(Some(#value)),
+        None => syn::parse_quote!(None),
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn has_nested_reference(ty: &syn::Type) -> bool {
+    fn has_reference(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '&',
+            proc_macro2::TokenTree::Group(group) => has_reference(group.stream()),
+            _ => false,
+        })
+    }
+    let referent = match ty {
+        syn::Type::Reference(reference) => &*reference.elem,
+        ty => ty,
+    };
+    has_reference(quote::ToTokens::to_token_stream(referent))
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn check_type(ty: &str) -> crate::BoxResult<()> {
+    syn::parse_str::<syn::Type>(ty).map_err(|err| ::alloc::format!("cxx-auto: invalid Rust type `{ty}`: {err}"))?;
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn check_ident(kind: &str, name: &str) -> crate::BoxResult<()> {
+    syn::parse_str::<syn::Ident>(name)
+        .map_err(|err| ::alloc::format!("cxx-auto: invalid Rust {kind} name `{name}`: {err}"))?;
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn emit_type(ty: &str) -> syn::Type {
+    syn::parse_str(ty).unwrap_or_else(|err| panic!("cxx-auto: invalid Rust type `{ty}`: {err}"))
+}
@@ -0,0 +1,77 @@
+use serde::Deserialize;
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoOverrides {
+    pub unpin: Option<bool>,
+    pub send: Option<bool>,
+    pub sync: Option<bool>,
+    pub copy: Option<bool>,
+    pub debug: Option<bool>,
+    pub default: Option<bool>,
+    pub display: Option<bool>,
+    pub drop: Option<bool>,
+    pub copy_new: Option<bool>,
+    pub move_new: Option<bool>,
+    pub eq: Option<bool>,
+    pub partial_eq: Option<bool>,
+    pub partial_ord: Option<bool>,
+    pub ord: Option<bool>,
+    pub hash: Option<bool>,
+    pub from_iterator: Option<bool>,
+    pub from_str: Option<bool>,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoOverrides {
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let unpin = emit_option_bool(self.unpin);
+        let send = emit_option_bool(self.send);
+        let sync = emit_option_bool(self.sync);
+        let copy = emit_option_bool(self.copy);
+        let debug = emit_option_bool(self.debug);
+        let default = emit_option_bool(self.default);
+        let display = emit_option_bool(self.display);
+        let drop = emit_option_bool(self.drop);
+        let copy_new = emit_option_bool(self.copy_new);
+        let move_new = emit_option_bool(self.move_new);
+        let eq = emit_option_bool(self.eq);
+        let partial_eq = emit_option_bool(self.partial_eq);
+        let partial_ord = emit_option_bool(self.partial_ord);
+        let ord = emit_option_bool(self.ord);
+        let hash = emit_option_bool(self.hash);
+        let from_iterator = emit_option_bool(self.from_iterator);
+        let from_str = emit_option_bool(self.from_str);
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoOverrides {
+                unpin: #unpin,
+                send: #send,
+                sync: #sync,
+                copy: #copy,
+                debug: #debug,
+                default: #default,
+                display: #display,
+                drop: #drop,
+                copy_new: #copy_new,
+                move_new: #move_new,
+                eq: #eq,
+                partial_eq: #partial_eq,
+                partial_ord: #partial_ord,
+                ord: #ord,
+                hash: #hash,
+                from_iterator: #from_iterator,
+                from_str: #from_str,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn emit_option_bool(value: Option<bool>) -> syn::Expr {
+    match value {
+        Some(value) => syn::parse_quote!(Some(#value)),
+        None => syn::parse_quote!(None),
+    }
+}
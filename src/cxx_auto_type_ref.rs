@@ -0,0 +1,104 @@
+use serde::Deserialize;
+
+// NOTE: builtin types that are passed through the `cxx` bridge by value rather than by reference
+const PRIMITIVE_TYPES: [&str; 13] = [
+    "bool", "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize", "f32", "f64",
+];
+
+// NOTE: a type reference without C++ names denotes one of the builtin `cxx` types (e.g., `u64` or `CxxString`)
+#[allow(clippy::module_name_repetitions)]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CxxAutoTypeRef<'ctx> {
+    pub rust_path: &'ctx str,
+    pub cxx_namespace: Option<&'ctx str>,
+    pub cxx_name: Option<&'ctx str>,
+}
+
+#[cfg(feature = "alloc")]
+impl CxxAutoTypeRef<'_> {
+    #[must_use]
+    pub fn is_primitive(&self) -> bool {
+        self.cxx_namespace.is_none() && PRIMITIVE_TYPES.contains(&self.rust_path)
+    }
+
+    // NOTE: `str` is passed through the `cxx` bridge as a `rust::Str` and compared in C++ as a `std::string_view`
+    #[must_use]
+    pub fn is_str(&self) -> bool {
+        self.cxx_namespace.is_none() && self.rust_path == "str"
+    }
+
+    pub(crate) fn emit_expr(&self) -> syn::Expr {
+        let rust_path = self.rust_path;
+        let cxx_namespace = crate::cxx_auto_method::emit_option_str(self.cxx_namespace);
+        let cxx_name = crate::cxx_auto_method::emit_option_str(self.cxx_name);
+        syn::parse_quote! {
+            ::cxx_auto::CxxAutoTypeRef {
+                rust_path: #rust_path,
+                cxx_namespace: #cxx_namespace,
+                cxx_name: #cxx_name,
+            }
+        }
+    }
+
+    pub(crate) fn check(&self) -> crate::BoxResult<()> {
+        let rust_path = self.rust_path;
+        let path = syn::parse_str::<syn::Path>(rust_path)
+            .map_err(|err| ::alloc::format!("cxx-auto: invalid Rust path `{rust_path}`: {err}"))?;
+        match (self.cxx_namespace, self.cxx_name) {
+            // NOTE: the path is used from within the generated `ffi` module, so it must not be relative
+            (Some(_), Some(_)) => {
+                let is_absolute =
+                    path.leading_colon.is_some() || path.segments.first().is_some_and(|segment| segment.ident == "crate");
+                if !is_absolute {
+                    return Err(
+                        ::alloc::format!("cxx-auto: Rust path `{rust_path}` must start with `crate::` or `::`").into(),
+                    );
+                }
+            },
+            (None, None) => {
+                if path.get_ident().is_none() {
+                    return Err(::alloc::format!(
+                        "cxx-auto: Rust path `{rust_path}` without `cxx_namespace` and `cxx_name` must name a builtin \
+                         `cxx` type"
+                    )
+                    .into());
+                }
+            },
+            _ => {
+                return Err(::alloc::format!(
+                    "cxx-auto: Rust path `{rust_path}` must specify both or neither of `cxx_namespace` and `cxx_name`"
+                )
+                .into());
+            },
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_path(&self) -> syn::Path {
+        let rust_path = self.rust_path;
+        syn::parse_str(rust_path).unwrap_or_else(|err| panic!("cxx-auto: invalid Rust path `{rust_path}`: {err}"))
+    }
+
+    pub(crate) fn emit_ident(&self) -> syn::Ident {
+        let path = self.emit_path();
+        match path.segments.last() {
+            Some(segment) => segment.ident.clone(),
+            None => panic!("cxx-auto: empty Rust path `{}`", self.rust_path),
+        }
+    }
+
+    // NOTE: the referenced type must itself be generated by cxx-auto, so that its `ExternType` id matches
+    pub(crate) fn emit_foreign_item_type(&self) -> Option<syn::ForeignItem> {
+        let cxx_namespace = self.cxx_namespace?;
+        let cxx_name = self.cxx_name?;
+        let ident = self.emit_ident();
+        let path = self.emit_path();
+        Some(syn::parse_quote! {
+            #[namespace = #cxx_namespace]
+            #[cxx_name = #cxx_name]
+            type #ident = #path;
+        })
+    }
+}
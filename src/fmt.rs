@@ -0,0 +1,184 @@
+use core::fmt::Write;
+
+/// Writes bytes produced by C++ formatting into `f`, replacing invalid UTF-8 sequences with `U+FFFD`.
+///
+/// # Errors
+///
+/// Will return `Err` if writing to `f` fails.
+pub fn write_utf8_lossy(f: &mut core::fmt::Formatter<'_>, mut bytes: &[u8]) -> core::fmt::Result {
+    loop {
+        match core::str::from_utf8(bytes) {
+            Ok(valid) => return f.write_str(valid),
+            Err(err) => {
+                let (valid, rest) = bytes.split_at(err.valid_up_to());
+                f.write_str(unsafe { core::str::from_utf8_unchecked(valid) })?;
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+                bytes = &rest[err.error_len().unwrap_or(rest.len()) ..];
+            },
+        }
+    }
+}
+
+/// Converts the status returned by `cxx_auto::cxx_display_format`.
+///
+/// # Errors
+///
+/// Will return `Err` if the C++ formatting failed, or if the `std::formatter` rejected the format spec.
+pub fn check_std_format(status: u8) -> core::fmt::Result {
+    match status {
+        0 => Ok(()),
+        _ => Err(core::fmt::Error),
+    }
+}
+
+/// Formats with a C++ `std::formatter`, passing the flags of `f` through as a `std::format` replacement field.
+///
+/// If the `std::formatter` rejects the replacement field, the value is formatted with `{}` instead and then padded
+/// like `Formatter::pad` does, which formats it twice (once to measure it and once to write it) rather than
+/// buffering the output.
+///
+/// # Errors
+///
+/// Will return `Err` if the C++ formatting fails or if writing to `f` fails.
+pub fn write_std_format<F>(f: &mut core::fmt::Formatter<'_>, format: F) -> core::fmt::Result
+where
+    F: Fn(&str, &mut core::fmt::Formatter<'_>) -> u8,
+{
+    struct Plain<F>(F);
+    impl<F> core::fmt::Display for Plain<F>
+    where
+        F: Fn(&str, &mut core::fmt::Formatter<'_>) -> u8,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            check_std_format((self.0)("{}", f))
+        }
+    }
+    let spec = FormatSpec::new(f);
+    match format(spec.as_str(), f) {
+        // NOTE: nothing is written before the replacement field is rejected, so the plain output can still be padded
+        2 => pad(f, &Plain(&format)),
+        status => check_std_format(status),
+    }
+}
+
+// NOTE: mirrors `Formatter::pad`, truncating to the precision and filling up to the width
+fn pad(f: &mut core::fmt::Formatter<'_>, value: &dyn core::fmt::Display) -> core::fmt::Result {
+    let padding = match f.width() {
+        Some(width) => {
+            let mut counter = Truncated::new(CharCount(0), f.precision());
+            core::fmt::write(&mut counter, format_args!("{value}"))?;
+            width.saturating_sub(counter.inner.0)
+        },
+        None => 0,
+    };
+    let (pre, post) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(core::fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0 .. pre {
+        f.write_char(fill)?;
+    }
+    let precision = f.precision();
+    core::fmt::write(&mut Truncated::new(&mut *f, precision), format_args!("{value}"))?;
+    for _ in 0 .. post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+struct CharCount(usize);
+
+impl core::fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+// NOTE: forwards at most `remaining` characters, or everything if there is no precision
+struct Truncated<W> {
+    inner: W,
+    remaining: Option<usize>,
+}
+
+impl<W> Truncated<W> {
+    fn new(inner: W, remaining: Option<usize>) -> Self {
+        Self { inner, remaining }
+    }
+}
+
+impl<W: core::fmt::Write> core::fmt::Write for Truncated<W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let Some(remaining) = &mut self.remaining else {
+            return self.inner.write_str(s);
+        };
+        let end = s.char_indices().nth(*remaining).map_or(s.len(), |(index, _)| index);
+        *remaining -= s[.. end].chars().count();
+        self.inner.write_str(&s[.. end])
+    }
+}
+
+/// A `std::format` replacement field (e.g., `{:*^+#010.3}`) carrying the flags of a Rust `Formatter`.
+pub struct FormatSpec {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl FormatSpec {
+    #[must_use]
+    pub fn new(f: &core::fmt::Formatter<'_>) -> Self {
+        let mut spec = Self { bytes: [0; 64], len: 0 };
+        // NOTE: the buffer is large enough for the longest possible field, so writing cannot fail
+        let _ = spec.write_fields(f);
+        spec
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[.. self.len]) }
+    }
+
+    fn write_fields(&mut self, f: &core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_str("{:")?;
+        if let Some(align) = f.align() {
+            // NOTE: braces are not allowed as a fill character by `std::format`
+            let fill = f.fill();
+            if fill != '{' && fill != '}' {
+                self.write_char(fill)?;
+            }
+            self.write_char(match align {
+                core::fmt::Alignment::Left => '<',
+                core::fmt::Alignment::Right => '>',
+                core::fmt::Alignment::Center => '^',
+            })?;
+        }
+        if f.sign_plus() {
+            self.write_char('+')?;
+        }
+        if f.alternate() {
+            self.write_char('#')?;
+        }
+        if f.sign_aware_zero_pad() {
+            self.write_char('0')?;
+        }
+        if let Some(width) = f.width() {
+            write!(self, "{width}")?;
+        }
+        if let Some(precision) = f.precision() {
+            write!(self, ".{precision}")?;
+        }
+        self.write_char('}')
+    }
+}
+
+impl core::fmt::Write for FormatSpec {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        let bytes = self.bytes.get_mut(self.len .. end).ok_or(core::fmt::Error)?;
+        bytes.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -9,7 +9,15 @@ extern crate alloc;
 extern crate std;
 
 mod cxx_auto_artifact_info;
+mod cxx_auto_conversion;
 mod cxx_auto_entry;
+mod cxx_auto_enum_repr;
+mod cxx_auto_field;
+mod cxx_auto_fmt_source;
+mod cxx_auto_index;
+mod cxx_auto_method;
+mod cxx_auto_overrides;
+mod cxx_auto_type_ref;
 mod error;
 mod ffi {
     pub(crate) mod ctypes;
@@ -20,10 +28,27 @@ mod gen {
 mod processing;
 
 #[cfg(feature = "alloc")]
-pub use crate::{cxx_auto_artifact_info::CxxAutoArtifactInfo, cxx_auto_entry::CxxAutoEntry, error::*};
+pub use crate::{
+    cxx_auto_artifact_info::CxxAutoArtifactInfo,
+    cxx_auto_conversion::CxxAutoConversion,
+    cxx_auto_entry::{CxxAutoEntry, CxxAutoEntryKind},
+    cxx_auto_enum_repr::CxxAutoEnumRepr,
+    cxx_auto_field::CxxAutoField,
+    cxx_auto_fmt_source::CxxAutoFmtSource,
+    cxx_auto_index::CxxAutoIndex,
+    cxx_auto_method::{CxxAutoArg, CxxAutoConstructor, CxxAutoMethod},
+    cxx_auto_overrides::CxxAutoOverrides,
+    cxx_auto_type_ref::CxxAutoTypeRef,
+    error::*,
+};
 #[cfg(feature = "alloc")]
 pub use indexmap;
 
+pub mod cmp;
+pub mod convert;
+pub mod fmt;
+pub mod parse;
+
 pub mod ctypes {
     pub use crate::ffi::ctypes::{
         c_char,
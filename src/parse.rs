@@ -0,0 +1,40 @@
+/// The reason a C++ `operator>>` extraction could not parse a string.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseError {
+    /// The extraction set `failbit`, e.g., because the input is malformed.
+    Fail,
+    /// The extraction set `badbit` or threw, leaving the stream unusable.
+    Bad,
+    /// The extraction succeeded but left non-whitespace input unconsumed.
+    Trailing,
+}
+
+impl ParseError {
+    /// Converts the status returned by `cxx_auto::cxx_parse_new`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `status` reports a failed extraction.
+    pub fn check(status: u8) -> Result<(), Self> {
+        match status {
+            0 => Ok(()),
+            1 => Err(Self::Fail),
+            3 => Err(Self::Trailing),
+            _ => Err(Self::Bad),
+        }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Fail => "C++ extraction failed to parse the input",
+            Self::Bad => "C++ extraction left the stream in an unrecoverable state",
+            Self::Trailing => "C++ extraction did not consume the entire input",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+}
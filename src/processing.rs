@@ -94,6 +94,7 @@ fn process_src_auto_sub_module(
                 skip_paths.insert(path.clone());
                 let text = std::fs::read_to_string(path)?;
                 let data = serde_json::from_str::<crate::CxxAutoEntry>(&text)?;
+                data.check()?;
                 items_write_module =
                     data.emit_items_write_module_for_file(path_components.iter(), path_descendants.iter());
                 item_mod_cxx_bridge.extend(data.emit_item_mod_cxx_bridge());